@@ -0,0 +1,114 @@
+use super::BrowserBackend;
+use crate::{
+    bookmarks::{self, BookmarkId, BookmarkTreeNode, Changes, CreateDetails, Destination, Query},
+    Error,
+};
+
+/// The operations of the [`bookmarks`](crate::bookmarks) API.
+#[allow(async_fn_in_trait)]
+pub trait BookmarksBackend {
+    /// See [`bookmarks::get`].
+    async fn get_bookmarks(&self, ids: &[BookmarkId]) -> Result<Vec<BookmarkTreeNode>, Error>;
+
+    /// See [`bookmarks::get_children`].
+    async fn get_bookmark_children(&self, id: &BookmarkId) -> Result<Vec<BookmarkTreeNode>, Error>;
+
+    /// See [`bookmarks::get_recent`].
+    async fn get_recent_bookmarks(
+        &self,
+        number_of_items: u32,
+    ) -> Result<Vec<BookmarkTreeNode>, Error>;
+
+    /// See [`bookmarks::get_tree`].
+    async fn get_bookmark_tree(&self) -> Result<Vec<BookmarkTreeNode>, Error>;
+
+    /// See [`bookmarks::get_sub_tree`].
+    async fn get_bookmark_sub_tree(&self, id: &BookmarkId) -> Result<Vec<BookmarkTreeNode>, Error>;
+
+    /// See [`bookmarks::search`].
+    async fn search_bookmarks(&self, query: &Query<'_>) -> Result<Vec<BookmarkTreeNode>, Error>;
+
+    /// See [`bookmarks::create`].
+    async fn create_bookmark(&self, details: &CreateDetails<'_>)
+        -> Result<BookmarkTreeNode, Error>;
+
+    /// See [`bookmarks::update`].
+    async fn update_bookmark(
+        &self,
+        id: &BookmarkId,
+        changes: &Changes<'_>,
+    ) -> Result<BookmarkTreeNode, Error>;
+
+    /// See [`bookmarks::move_node`].
+    async fn move_bookmark(
+        &self,
+        id: &BookmarkId,
+        destination: &Destination<'_>,
+    ) -> Result<BookmarkTreeNode, Error>;
+
+    /// See [`bookmarks::remove`].
+    async fn remove_bookmark(&self, id: &BookmarkId) -> Result<(), Error>;
+
+    /// See [`bookmarks::remove_tree`].
+    async fn remove_bookmark_tree(&self, id: &BookmarkId) -> Result<(), Error>;
+}
+
+impl BookmarksBackend for BrowserBackend {
+    async fn get_bookmarks(&self, ids: &[BookmarkId]) -> Result<Vec<BookmarkTreeNode>, Error> {
+        bookmarks::get(ids).await
+    }
+
+    async fn get_bookmark_children(&self, id: &BookmarkId) -> Result<Vec<BookmarkTreeNode>, Error> {
+        bookmarks::get_children(id).await
+    }
+
+    async fn get_recent_bookmarks(
+        &self,
+        number_of_items: u32,
+    ) -> Result<Vec<BookmarkTreeNode>, Error> {
+        bookmarks::get_recent(number_of_items).await
+    }
+
+    async fn get_bookmark_tree(&self) -> Result<Vec<BookmarkTreeNode>, Error> {
+        bookmarks::get_tree().await
+    }
+
+    async fn get_bookmark_sub_tree(&self, id: &BookmarkId) -> Result<Vec<BookmarkTreeNode>, Error> {
+        bookmarks::get_sub_tree(id).await
+    }
+
+    async fn search_bookmarks(&self, query: &Query<'_>) -> Result<Vec<BookmarkTreeNode>, Error> {
+        bookmarks::search(query).await
+    }
+
+    async fn create_bookmark(
+        &self,
+        details: &CreateDetails<'_>,
+    ) -> Result<BookmarkTreeNode, Error> {
+        bookmarks::create(details).await
+    }
+
+    async fn update_bookmark(
+        &self,
+        id: &BookmarkId,
+        changes: &Changes<'_>,
+    ) -> Result<BookmarkTreeNode, Error> {
+        bookmarks::update(id, changes).await
+    }
+
+    async fn move_bookmark(
+        &self,
+        id: &BookmarkId,
+        destination: &Destination<'_>,
+    ) -> Result<BookmarkTreeNode, Error> {
+        bookmarks::move_node(id, destination).await
+    }
+
+    async fn remove_bookmark(&self, id: &BookmarkId) -> Result<(), Error> {
+        bookmarks::remove(id).await
+    }
+
+    async fn remove_bookmark_tree(&self, id: &BookmarkId) -> Result<(), Error> {
+        bookmarks::remove_tree(id).await
+    }
+}
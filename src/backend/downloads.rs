@@ -0,0 +1,71 @@
+use super::BrowserBackend;
+use crate::{
+    downloads::{self, DownloadId, DownloadItem, DownloadOptions, DownloadQuery},
+    Error, Timestamp,
+};
+
+/// The operations of the [`downloads`](crate::downloads) API.
+#[allow(async_fn_in_trait)]
+pub trait DownloadsBackend {
+    /// See [`downloads::search`].
+    async fn search_downloads(&self, query: &DownloadQuery<'_>)
+        -> Result<Vec<DownloadItem>, Error>;
+
+    /// See [`downloads::download`].
+    async fn download(&self, options: &DownloadOptions<'_>) -> Result<DownloadId, Error>;
+
+    /// See [`downloads::pause`].
+    async fn pause_download(&self, download_id: DownloadId) -> Result<(), Error>;
+
+    /// See [`downloads::resume`].
+    async fn resume_download(&self, download_id: DownloadId) -> Result<(), Error>;
+
+    /// See [`downloads::cancel`].
+    async fn cancel_download(&self, download_id: DownloadId) -> Result<(), Error>;
+
+    /// See [`downloads::erase`].
+    async fn erase_downloads(&self, query: &DownloadQuery<'_>) -> Result<Vec<DownloadId>, Error>;
+
+    /// See [`downloads::remove_file`].
+    async fn remove_download_file(&self, download_id: DownloadId) -> Result<(), Error>;
+
+    /// The current time, which is used to measure the speed of downloads.
+    fn now(&self) -> Timestamp;
+}
+
+impl DownloadsBackend for BrowserBackend {
+    async fn search_downloads(
+        &self,
+        query: &DownloadQuery<'_>,
+    ) -> Result<Vec<DownloadItem>, Error> {
+        downloads::search(query).await
+    }
+
+    async fn download(&self, options: &DownloadOptions<'_>) -> Result<DownloadId, Error> {
+        downloads::download(options).await
+    }
+
+    async fn pause_download(&self, download_id: DownloadId) -> Result<(), Error> {
+        downloads::pause(download_id).await
+    }
+
+    async fn resume_download(&self, download_id: DownloadId) -> Result<(), Error> {
+        downloads::resume(download_id).await
+    }
+
+    async fn cancel_download(&self, download_id: DownloadId) -> Result<(), Error> {
+        downloads::cancel(download_id).await
+    }
+
+    async fn erase_downloads(&self, query: &DownloadQuery<'_>) -> Result<Vec<DownloadId>, Error> {
+        downloads::erase(query).await
+    }
+
+    async fn remove_download_file(&self, download_id: DownloadId) -> Result<(), Error> {
+        downloads::remove_file(download_id).await
+    }
+
+    fn now(&self) -> Timestamp {
+        Timestamp::from_millis(js_sys::Date::now())
+    }
+}
@@ -0,0 +1,278 @@
+use super::{error, matches_words, FakeBackend, State};
+use crate::{
+    backend::BookmarksBackend,
+    bookmarks::{BookmarkId, BookmarkTreeNode, Changes, CreateDetails, Destination, Query},
+    Error,
+};
+
+/// The folder of new bookmarks without parent, i.e. "Other bookmarks".
+const DEFAULT_PARENT_ID: &str = "2";
+
+fn find_mut<'a>(
+    node: &'a mut BookmarkTreeNode,
+    id: &BookmarkId,
+) -> Option<&'a mut BookmarkTreeNode> {
+    if node.id == *id {
+        return Some(node);
+    }
+    node.children
+        .iter_mut()
+        .flatten()
+        .find_map(|child| find_mut(child, id))
+}
+
+/// Updates the index and parent of the children after they were inserted, moved or removed.
+fn reindex(folder: &mut BookmarkTreeNode) {
+    for (index, child) in folder.children.iter_mut().flatten().enumerate() {
+        child.index = Some(index as u32);
+        child.parent_id = Some(folder.id.clone());
+    }
+}
+
+/// The node as returned by all methods but `getTree` and `getSubTree`.
+fn without_children(node: &BookmarkTreeNode) -> BookmarkTreeNode {
+    BookmarkTreeNode {
+        children: None,
+        ..node.clone()
+    }
+}
+
+impl State {
+    fn bookmark(&self, id: &BookmarkId) -> Result<&BookmarkTreeNode, Error> {
+        self.bookmarks
+            .find_by_id(id)
+            .ok_or_else(|| error("Can't find bookmark for id."))
+    }
+
+    fn bookmark_mut(&mut self, id: &BookmarkId) -> Result<&mut BookmarkTreeNode, Error> {
+        find_mut(&mut self.bookmarks, id).ok_or_else(|| error("Can't find bookmark for id."))
+    }
+
+    fn bookmark_folder_mut(&mut self, id: &BookmarkId) -> Result<&mut BookmarkTreeNode, Error> {
+        let folder = find_mut(&mut self.bookmarks, id)
+            .ok_or_else(|| error("Can't find parent bookmark for id."))?;
+        if !folder.is_folder() {
+            return Err(error("Parameter 'parentId' does not specify a folder."));
+        }
+        Ok(folder)
+    }
+
+    /// Fails for the root and its folders, which can't be modified.
+    fn check_modifiable(&self, id: &BookmarkId) -> Result<(), Error> {
+        let node = self.bookmark(id)?;
+        if node.parent_id.is_none() || node.parent_id.as_ref() == Some(&self.bookmarks.id) {
+            return Err(error("Can't modify the root bookmark folders."));
+        }
+        Ok(())
+    }
+
+    fn insert_bookmark(
+        &mut self,
+        parent_id: &BookmarkId,
+        index: Option<u32>,
+        node: BookmarkTreeNode,
+    ) -> Result<BookmarkTreeNode, Error> {
+        let now = self.now;
+        let id = node.id.clone();
+        let parent = self.bookmark_folder_mut(parent_id)?;
+        let children = parent.children.get_or_insert_with(Vec::new);
+        let index = match index {
+            Some(index) if index as usize > children.len() => {
+                return Err(error("Index out of bounds."))
+            }
+            Some(index) => index as usize,
+            None => children.len(),
+        };
+        children.insert(index, node);
+        parent.date_group_modified = Some(now);
+        reindex(parent);
+        Ok(without_children(self.bookmark(&id)?))
+    }
+
+    fn detach_bookmark(&mut self, id: &BookmarkId) -> Result<BookmarkTreeNode, Error> {
+        let now = self.now;
+        let node = self.bookmark(id)?;
+        let parent_id = node.parent_id.clone().expect("the root can't be detached");
+        let index = node.index.unwrap_or_default() as usize;
+        let parent = self.bookmark_mut(&parent_id)?;
+        let node = parent.children.get_or_insert_with(Vec::new).remove(index);
+        parent.date_group_modified = Some(now);
+        reindex(parent);
+        Ok(node)
+    }
+}
+
+impl BookmarksBackend for FakeBackend {
+    async fn get_bookmarks(&self, ids: &[BookmarkId]) -> Result<Vec<BookmarkTreeNode>, Error> {
+        let state = self.state.borrow();
+        ids.iter()
+            .map(|id| state.bookmark(id).map(without_children))
+            .collect()
+    }
+
+    async fn get_bookmark_children(&self, id: &BookmarkId) -> Result<Vec<BookmarkTreeNode>, Error> {
+        let state = self.state.borrow();
+        Ok(state
+            .bookmark(id)?
+            .child_nodes()
+            .iter()
+            .map(without_children)
+            .collect())
+    }
+
+    async fn get_recent_bookmarks(
+        &self,
+        number_of_items: u32,
+    ) -> Result<Vec<BookmarkTreeNode>, Error> {
+        let state = self.state.borrow();
+        let mut bookmarks: Vec<_> = state
+            .bookmarks
+            .iter_depth_first()
+            .filter(|node| !node.is_folder())
+            .collect();
+        // Bookmarks that were added at the same time are ordered by their creation.
+        let creation_order = |node: &BookmarkTreeNode| node.id.as_str().parse::<u64>().ok();
+        bookmarks.sort_by(|a, b| {
+            b.date_added
+                .cmp(&a.date_added)
+                .then_with(|| creation_order(b).cmp(&creation_order(a)))
+        });
+        Ok(bookmarks
+            .into_iter()
+            .take(number_of_items as usize)
+            .map(without_children)
+            .collect())
+    }
+
+    async fn get_bookmark_tree(&self) -> Result<Vec<BookmarkTreeNode>, Error> {
+        Ok(vec![self.state.borrow().bookmarks.clone()])
+    }
+
+    async fn get_bookmark_sub_tree(&self, id: &BookmarkId) -> Result<Vec<BookmarkTreeNode>, Error> {
+        Ok(vec![self.state.borrow().bookmark(id)?.clone()])
+    }
+
+    /// Matches the words of [`Query::query`] against the titles and URLs, ignoring case,
+    /// and [`Query::title`] and [`Query::url`] exactly.
+    async fn search_bookmarks(&self, query: &Query<'_>) -> Result<Vec<BookmarkTreeNode>, Error> {
+        let state = self.state.borrow();
+        Ok(state
+            .bookmarks
+            .child_nodes()
+            .iter()
+            .flat_map(|folder| folder.iter_depth_first().skip(1))
+            .filter(|node| {
+                let fields = [node.title.as_str(), node.url.as_deref().unwrap_or_default()];
+                query
+                    .query
+                    .map_or(true, |query| matches_words(query, fields))
+                    && query.title.map_or(true, |title| node.title == title)
+                    && query
+                        .url
+                        .map_or(true, |url| node.url.as_deref() == Some(url))
+            })
+            .map(without_children)
+            .collect())
+    }
+
+    /// Creates the node in "Other bookmarks", unless [`CreateDetails::parent_id`] is set.
+    async fn create_bookmark(
+        &self,
+        details: &CreateDetails<'_>,
+    ) -> Result<BookmarkTreeNode, Error> {
+        let mut state = self.state.borrow_mut();
+        let id = BookmarkId::from(state.next_bookmark_id.to_string());
+        let parent_id = details
+            .parent_id
+            .cloned()
+            .unwrap_or_else(|| DEFAULT_PARENT_ID.into());
+        let node = BookmarkTreeNode {
+            id,
+            children: details.url.is_none().then(Vec::new),
+            index: None,
+            parent_id: None,
+            date_added: Some(state.now),
+            date_group_modified: None,
+            title: details.title.unwrap_or_default().to_owned(),
+            url: details.url.map(ToOwned::to_owned),
+        };
+        let node = state.insert_bookmark(&parent_id, details.index, node)?;
+        state.next_bookmark_id += 1;
+        Ok(node)
+    }
+
+    async fn update_bookmark(
+        &self,
+        id: &BookmarkId,
+        changes: &Changes<'_>,
+    ) -> Result<BookmarkTreeNode, Error> {
+        let mut state = self.state.borrow_mut();
+        state.check_modifiable(id)?;
+        let node = state.bookmark_mut(id)?;
+        if let Some(url) = changes.url {
+            if node.is_folder() {
+                return Err(error("Can't set URL of a bookmark folder."));
+            }
+            node.url = Some(url.to_owned());
+        }
+        if let Some(title) = changes.title {
+            node.title = title.to_owned();
+        }
+        Ok(without_children(node))
+    }
+
+    /// Moves the node to the end of its folder, unless [`Destination::index`] is set.
+    ///
+    /// Like in Chrome, the index refers to the position before the node is removed
+    /// from its current folder.
+    async fn move_bookmark(
+        &self,
+        id: &BookmarkId,
+        destination: &Destination<'_>,
+    ) -> Result<BookmarkTreeNode, Error> {
+        let mut state = self.state.borrow_mut();
+        state.check_modifiable(id)?;
+        let node = state.bookmark(id)?;
+        let old_parent_id = node.parent_id.clone();
+        let old_index = node.index;
+        let parent_id = destination
+            .parent_id
+            .cloned()
+            .or_else(|| old_parent_id.clone())
+            .expect("modifiable nodes have a parent");
+        if node.find_by_id(&parent_id).is_some() {
+            return Err(error("Can't move a folder into itself or its descendants."));
+        }
+        state.bookmark_folder_mut(&parent_id)?;
+        let index = match (destination.index, old_index) {
+            (Some(index), Some(old_index))
+                if Some(&parent_id) == old_parent_id.as_ref() && index > old_index =>
+            {
+                Some(index - 1)
+            }
+            (index, _) => index,
+        };
+        let node = state.detach_bookmark(id)?;
+        state.insert_bookmark(&parent_id, index, node)
+    }
+
+    /// Removes a bookmark or an empty folder.
+    async fn remove_bookmark(&self, id: &BookmarkId) -> Result<(), Error> {
+        let mut state = self.state.borrow_mut();
+        state.check_modifiable(id)?;
+        if !state.bookmark(id)?.child_nodes().is_empty() {
+            return Err(error(
+                "Can't remove non-empty folder (use recursive to remove a folder with children).",
+            ));
+        }
+        state.detach_bookmark(id)?;
+        Ok(())
+    }
+
+    async fn remove_bookmark_tree(&self, id: &BookmarkId) -> Result<(), Error> {
+        let mut state = self.state.borrow_mut();
+        state.check_modifiable(id)?;
+        state.detach_bookmark(id)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,294 @@
+use super::{error, FakeBackend, State};
+use crate::{
+    backend::DownloadsBackend,
+    downloads::{
+        DangerType, DownloadId, DownloadItem, DownloadOptions, DownloadQuery,
+        FilenameConflictAction, InterruptReason, State as DownloadState,
+    },
+    Error, Timestamp,
+};
+use std::{cmp::Ordering, path::PathBuf};
+
+/// The default of [`DownloadQuery::limit`].
+const DEFAULT_LIMIT: u32 = 1000;
+
+impl State {
+    pub(super) fn download_mut(
+        &mut self,
+        download_id: DownloadId,
+    ) -> Result<&mut DownloadItem, Error> {
+        self.downloads
+            .iter_mut()
+            .find(|item| item.id == download_id)
+            .ok_or_else(|| error(format!("Invalid download id: {}.", download_id.0)))
+    }
+
+    /// Appends ` (1)`, ` (2)`, … to the file stem until the name is not taken.
+    fn unique_filename(&self, filename: PathBuf) -> PathBuf {
+        let is_taken = |filename: &PathBuf| {
+            self.downloads
+                .iter()
+                .any(|item| item.exists && item.filename == *filename)
+        };
+        if !is_taken(&filename) {
+            return filename;
+        }
+        let stem = filename
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .into_owned();
+        let extension = filename
+            .extension()
+            .map(|extension| format!(".{}", extension.to_string_lossy()))
+            .unwrap_or_default();
+        (1..)
+            .map(|n| filename.with_file_name(format!("{stem} ({n}){extension}")))
+            .find(|filename| !is_taken(filename))
+            .expect("an unused filename")
+    }
+
+    fn search_downloads(&self, query: &DownloadQuery<'_>) -> Result<Vec<&DownloadItem>, Error> {
+        let regexes = [query.filename_regex, query.final_url_regex, query.url_regex];
+        if regexes.iter().any(Option::is_some) {
+            return Err(error(
+                "Regular expressions are not supported by the fake backend.",
+            ));
+        }
+        if query.danger == Some(DangerType::Other) || query.error == Some(InterruptReason::Other) {
+            // Like serializing the query for the browser.
+            return Err(error("Unknown values cannot be queried."));
+        }
+        let mut items: Vec<_> = self
+            .downloads
+            .iter()
+            .filter(|item| matches(item, query))
+            .collect();
+        for key in query.order_by.iter().flatten().rev() {
+            let (key, descending) = match key.strip_prefix('-') {
+                Some(key) => (key, true),
+                None => (*key, false),
+            };
+            let compare =
+                order_by(key).ok_or_else(|| error(format!("Unsupported orderBy key: {key}.")))?;
+            items.sort_by(|a, b| {
+                let ordering = compare(a, b);
+                if descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            });
+        }
+        let limit = query.limit.unwrap_or(DEFAULT_LIMIT);
+        if limit > 0 {
+            items.truncate(limit as usize);
+        }
+        Ok(items)
+    }
+}
+
+fn matches(item: &DownloadItem, query: &DownloadQuery<'_>) -> bool {
+    fn eq<T: PartialEq>(expected: Option<T>, actual: T) -> bool {
+        expected.map_or(true, |expected| expected == actual)
+    }
+    let filename = item.filename.to_string_lossy();
+    let terms_match = query.query.iter().flatten().all(|term| {
+        let (term, excluded) = match term.strip_prefix('-') {
+            Some(term) => (term, true),
+            None => (*term, false),
+        };
+        let term = term.to_lowercase();
+        let found =
+            filename.to_lowercase().contains(&term) || item.url.to_lowercase().contains(&term);
+        found != excluded
+    });
+    terms_match
+        && eq(query.bytes_received, item.bytes_received)
+        && eq(query.danger, item.danger)
+        && eq(query.end_time.map(Some), item.end_time)
+        && query.ended_after.map_or(true, |time| {
+            item.end_time.is_some_and(|end_time| end_time > time)
+        })
+        && query.ended_before.map_or(true, |time| {
+            item.end_time.is_some_and(|end_time| end_time < time)
+        })
+        && eq(query.error.map(Some), item.error)
+        && eq(query.exists, item.exists)
+        && eq(query.file_size, item.file_size)
+        && eq(query.filename, &*filename)
+        && eq(query.final_url.map(Some), item.final_url.as_deref())
+        && eq(query.id, item.id)
+        && eq(query.mime, item.mime.as_str())
+        && eq(query.paused, item.paused)
+        && eq(query.start_time, item.start_time)
+        && query
+            .started_after
+            .map_or(true, |time| item.start_time > time)
+        && query
+            .started_before
+            .map_or(true, |time| item.start_time < time)
+        && eq(query.state, item.state)
+        && eq(query.total_bytes, item.total_bytes)
+        && query
+            .total_bytes_greater
+            .map_or(true, |bytes| item.total_bytes > bytes)
+        && query
+            .total_bytes_less
+            .map_or(true, |bytes| item.total_bytes < bytes)
+        && eq(query.url, item.url.as_str())
+}
+
+type Compare = fn(&&DownloadItem, &&DownloadItem) -> Ordering;
+
+fn order_by(key: &str) -> Option<Compare> {
+    let compare: Compare = match key {
+        "bytesReceived" => |a, b| a.bytes_received.cmp(&b.bytes_received),
+        "endTime" => |a, b| a.end_time.cmp(&b.end_time),
+        "fileSize" => |a, b| a.file_size.cmp(&b.file_size),
+        "filename" => |a, b| a.filename.cmp(&b.filename),
+        "finalUrl" => |a, b| a.final_url.cmp(&b.final_url),
+        "id" => |a, b| a.id.0.cmp(&b.id.0),
+        "mime" => |a, b| a.mime.cmp(&b.mime),
+        "startTime" => |a, b| a.start_time.cmp(&b.start_time),
+        "totalBytes" => |a, b| a.total_bytes.cmp(&b.total_bytes),
+        "url" => |a, b| a.url.cmp(&b.url),
+        _ => return None,
+    };
+    Some(compare)
+}
+
+/// The last segment of the URL path, e.g. `file.zip` of `https://example.com/file.zip?a=1`.
+fn filename_from_url(url: &str) -> &str {
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+    let path = path.split_once("://").map_or(path, |(_, path)| path);
+    match path.split_once('/') {
+        Some((_, path)) => path.rsplit('/').next().unwrap_or_default(),
+        None => "",
+    }
+}
+
+impl DownloadsBackend for FakeBackend {
+    /// Supports all filters but the regular expressions.
+    ///
+    /// [`DownloadQuery::order_by`] supports `bytesReceived`, `endTime`, `fileSize`,
+    /// `filename`, `finalUrl`, `id`, `mime`, `startTime`, `totalBytes` and `url`.
+    async fn search_downloads(
+        &self,
+        query: &DownloadQuery<'_>,
+    ) -> Result<Vec<DownloadItem>, Error> {
+        let state = self.state.borrow();
+        let items = state.search_downloads(query)?;
+        Ok(items.into_iter().cloned().collect())
+    }
+
+    /// Starts a download that makes no progress until it is
+    /// [updated](FakeBackend::update_download).
+    ///
+    /// Without [`DownloadOptions::filename`] the file is named after the URL.
+    async fn download(&self, options: &DownloadOptions<'_>) -> Result<DownloadId, Error> {
+        let mut state = self.state.borrow_mut();
+        let filename = match options.filename {
+            Some(filename) => filename,
+            None => match filename_from_url(options.url) {
+                "" => "download",
+                filename => filename,
+            },
+        };
+        let filename = match options.conflict_action {
+            Some(FilenameConflictAction::Overwrite) => PathBuf::from(filename),
+            _ => state.unique_filename(PathBuf::from(filename)),
+        };
+        let id = DownloadId(state.next_download_id);
+        state.next_download_id += 1;
+        let item = DownloadItem {
+            by_extension_id: None,
+            by_extension_name: None,
+            bytes_received: 0,
+            can_resume: false,
+            danger: DangerType::Safe,
+            end_time: None,
+            error: None,
+            estimated_end_time: None,
+            exists: true,
+            file_size: 0,
+            filename,
+            final_url: Some(options.url.to_owned()),
+            id,
+            incognito: false,
+            mime: String::new(),
+            paused: false,
+            referrer: None,
+            start_time: state.now,
+            state: DownloadState::InProgress,
+            total_bytes: 0,
+            url: options.url.to_owned(),
+        };
+        state.downloads.push(item);
+        Ok(id)
+    }
+
+    async fn pause_download(&self, download_id: DownloadId) -> Result<(), Error> {
+        let mut state = self.state.borrow_mut();
+        let item = state.download_mut(download_id)?;
+        if item.state != DownloadState::InProgress {
+            return Err(error("Download must be in progress."));
+        }
+        item.paused = true;
+        item.can_resume = true;
+        Ok(())
+    }
+
+    async fn resume_download(&self, download_id: DownloadId) -> Result<(), Error> {
+        let mut state = self.state.borrow_mut();
+        let item = state.download_mut(download_id)?;
+        if !item.can_resume {
+            return Err(error("Download must be resumable."));
+        }
+        item.state = DownloadState::InProgress;
+        item.paused = false;
+        item.error = None;
+        Ok(())
+    }
+
+    /// Interrupts a download that is in progress, does nothing otherwise.
+    async fn cancel_download(&self, download_id: DownloadId) -> Result<(), Error> {
+        let mut state = self.state.borrow_mut();
+        let now = state.now;
+        let item = state.download_mut(download_id)?;
+        if item.state == DownloadState::InProgress {
+            item.state = DownloadState::Interrupted;
+            item.error = Some(InterruptReason::UserCanceled);
+            item.paused = false;
+            item.can_resume = false;
+            item.end_time = Some(now);
+        }
+        Ok(())
+    }
+
+    async fn erase_downloads(&self, query: &DownloadQuery<'_>) -> Result<Vec<DownloadId>, Error> {
+        let mut state = self.state.borrow_mut();
+        let ids: Vec<_> = state
+            .search_downloads(query)?
+            .into_iter()
+            .map(|item| item.id)
+            .collect();
+        state.downloads.retain(|item| !ids.contains(&item.id));
+        Ok(ids)
+    }
+
+    async fn remove_download_file(&self, download_id: DownloadId) -> Result<(), Error> {
+        let mut state = self.state.borrow_mut();
+        let item = state.download_mut(download_id)?;
+        if item.state != DownloadState::Complete {
+            return Err(error("Download must be complete."));
+        }
+        item.exists = false;
+        Ok(())
+    }
+
+    /// See [`FakeBackend::now`].
+    fn now(&self) -> Timestamp {
+        FakeBackend::now(self)
+    }
+}
@@ -0,0 +1,164 @@
+use super::{matches_words, FakeBackend, HistoryEntry, State};
+use crate::{
+    backend::HistoryBackend,
+    history::{HistoryItem, Query, TransitionType, VisitItem},
+    Error, Timestamp,
+};
+
+/// The default of [`Query::start_time`], i.e. 24 hours.
+const DEFAULT_SEARCH_PERIOD_MS: f64 = 24.0 * 60.0 * 60.0 * 1000.0;
+
+/// The default of [`Query::max_results`].
+const DEFAULT_MAX_RESULTS: usize = 100;
+
+impl HistoryEntry {
+    /// Updates the counts and the last visit time after visits were added or removed.
+    fn update_item(&mut self) {
+        self.item.visit_count = Some(self.visits.len());
+        self.item.typed_count = Some(
+            self.visits
+                .iter()
+                .filter(|visit| visit.transition == TransitionType::Typed)
+                .count(),
+        );
+        self.item.last_visit_time = self
+            .visits
+            .iter()
+            .filter_map(|visit| visit.visit_time)
+            .max();
+    }
+}
+
+impl State {
+    pub(super) fn record_visit(
+        &mut self,
+        url: &str,
+        title: Option<&str>,
+        transition: TransitionType,
+    ) {
+        let position = match self
+            .history
+            .iter()
+            .position(|entry| entry.item.url.as_deref() == Some(url))
+        {
+            Some(position) => position,
+            None => {
+                let id = self.next_history_id.to_string();
+                self.next_history_id += 1;
+                self.history.push(HistoryEntry {
+                    item: HistoryItem {
+                        id,
+                        last_visit_time: None,
+                        title: None,
+                        typed_count: None,
+                        url: Some(url.to_owned()),
+                        visit_count: None,
+                    },
+                    visits: vec![],
+                });
+                self.history.len() - 1
+            }
+        };
+        let visit_id = self.next_history_id.to_string();
+        self.next_history_id += 1;
+        let now = self.now;
+        let entry = &mut self.history[position];
+        if let Some(title) = title {
+            entry.item.title = Some(title.to_owned());
+        }
+        entry.visits.push(VisitItem {
+            id: entry.item.id.clone(),
+            visit_id,
+            visit_time: Some(now),
+            referring_visit_id: "0".to_owned(),
+            transition,
+            is_local: Some(true),
+        });
+        entry.update_item();
+    }
+}
+
+impl HistoryBackend for FakeBackend {
+    /// Returns the pages that were visited in the given period,
+    /// most recently visited first.
+    ///
+    /// Like in the browser, the period starts 24 hours ago and at most
+    /// 100 pages are returned, unless specified otherwise.
+    async fn search_history(&self, query: &Query<'_>) -> Result<Vec<HistoryItem>, Error> {
+        let state = self.state.borrow();
+        let start_time = query.start_time.unwrap_or(Timestamp::from_millis(
+            state.now.as_millis() - DEFAULT_SEARCH_PERIOD_MS,
+        ));
+        let in_period = |time: Timestamp| {
+            start_time <= time && query.end_time.map_or(true, |end_time| time < end_time)
+        };
+        let mut items: Vec<_> = state
+            .history
+            .iter()
+            .filter(|entry| {
+                entry
+                    .visits
+                    .iter()
+                    .filter_map(|visit| visit.visit_time)
+                    .any(in_period)
+            })
+            .filter(|entry| {
+                let fields = [
+                    entry.item.url.as_deref().unwrap_or_default(),
+                    entry.item.title.as_deref().unwrap_or_default(),
+                ];
+                matches_words(query.text, fields)
+            })
+            .map(|entry| entry.item.clone())
+            .collect();
+        items.sort_by_key(|item| std::cmp::Reverse(item.last_visit_time));
+        items.truncate(query.max_results.unwrap_or(DEFAULT_MAX_RESULTS));
+        Ok(items)
+    }
+
+    async fn get_visits(&self, url: &str) -> Result<Vec<VisitItem>, Error> {
+        Ok(self
+            .state
+            .borrow()
+            .history
+            .iter()
+            .find(|entry| entry.item.url.as_deref() == Some(url))
+            .map(|entry| entry.visits.clone())
+            .unwrap_or_default())
+    }
+
+    /// Records a [link](TransitionType::Link) visit at the current time.
+    async fn add_url(&self, url: &str) -> Result<(), Error> {
+        self.visit(url, None, TransitionType::Link);
+        Ok(())
+    }
+
+    async fn delete_url(&self, url: &str) -> Result<(), Error> {
+        self.state
+            .borrow_mut()
+            .history
+            .retain(|entry| entry.item.url.as_deref() != Some(url));
+        Ok(())
+    }
+
+    /// Removes the visits between `start_time` and `end_time`, both inclusive,
+    /// and the pages that have no visits left.
+    async fn delete_range(&self, start_time: Timestamp, end_time: Timestamp) -> Result<(), Error> {
+        let mut state = self.state.borrow_mut();
+        for entry in &mut state.history {
+            entry.visits.retain(|visit| {
+                visit
+                    .visit_time
+                    .map_or(true, |time| time < start_time || time > end_time)
+            });
+            entry.update_item();
+        }
+        state.history.retain(|entry| !entry.visits.is_empty());
+        Ok(())
+    }
+
+    async fn delete_all_history(&self) -> Result<(), Error> {
+        self.state.borrow_mut().history.clear();
+        Ok(())
+    }
+}
@@ -0,0 +1,175 @@
+use crate::{
+    bookmarks::{BookmarkId, BookmarkTreeNode},
+    downloads::{DownloadId, DownloadItem},
+    history::{HistoryItem, TransitionType, VisitItem},
+    tabs::Tab,
+    windows::{Window, WindowId},
+    Error, Timestamp,
+};
+use std::cell::RefCell;
+
+mod bookmarks;
+mod downloads;
+mod history;
+mod tabs;
+
+/// An in-memory backend that models the state of a browser.
+///
+/// A new backend has no windows, the folders "Bookmarks bar" (`"1"`)
+/// and "Other bookmarks" (`"2"`), no history and no downloads.
+/// Its clock stands still at [`Timestamp::UNIX_EPOCH`] until it is
+/// [set](Self::set_now), which keeps tests deterministic.
+///
+/// Failing operations return [`Error::Backend`] with a message
+/// similar to the one of the browser.
+#[derive(Debug)]
+pub struct FakeBackend {
+    state: RefCell<State>,
+}
+
+#[derive(Debug)]
+struct State {
+    now: Timestamp,
+    next_id: i32,
+    windows: Vec<FakeWindow>,
+    last_focused: Option<WindowId>,
+    bookmarks: BookmarkTreeNode,
+    next_bookmark_id: u64,
+    history: Vec<HistoryEntry>,
+    next_history_id: u64,
+    downloads: Vec<DownloadItem>,
+    next_download_id: i32,
+}
+
+/// A window together with its tabs in the order of their index.
+#[derive(Debug)]
+struct FakeWindow {
+    window: Window,
+    tabs: Vec<Tab>,
+}
+
+#[derive(Debug)]
+struct HistoryEntry {
+    item: HistoryItem,
+    visits: Vec<VisitItem>,
+}
+
+impl FakeBackend {
+    pub fn new() -> Self {
+        let root_id = BookmarkId::from("0");
+        let folder = |id: &str, index, title: &str| BookmarkTreeNode {
+            id: id.into(),
+            children: Some(vec![]),
+            index: Some(index),
+            parent_id: Some(root_id.clone()),
+            date_added: Some(Timestamp::UNIX_EPOCH),
+            date_group_modified: None,
+            title: title.to_owned(),
+            url: None,
+        };
+        let bookmarks = BookmarkTreeNode {
+            children: Some(vec![
+                folder("1", 0, "Bookmarks bar"),
+                folder("2", 1, "Other bookmarks"),
+            ]),
+            id: root_id,
+            index: None,
+            parent_id: None,
+            date_added: Some(Timestamp::UNIX_EPOCH),
+            date_group_modified: None,
+            title: String::new(),
+            url: None,
+        };
+        Self {
+            state: RefCell::new(State {
+                now: Timestamp::UNIX_EPOCH,
+                next_id: 1,
+                windows: vec![],
+                last_focused: None,
+                bookmarks,
+                next_bookmark_id: 3,
+                history: vec![],
+                next_history_id: 1,
+                downloads: vec![],
+                next_download_id: 1,
+            }),
+        }
+    }
+
+    /// The current time of the backend.
+    pub fn now(&self) -> Timestamp {
+        self.state.borrow().now
+    }
+
+    /// Sets the time that is used for new bookmarks, visits and downloads.
+    pub fn set_now(&self, now: Timestamp) {
+        self.state.borrow_mut().now = now;
+    }
+
+    /// Records a visit of `url` at the [current time](Self::now),
+    /// as if the user navigated to it.
+    ///
+    /// The title of the page is only changed if `title` is given.
+    pub fn visit(&self, url: &str, title: Option<&str>, transition: TransitionType) {
+        self.state.borrow_mut().record_visit(url, title, transition);
+    }
+
+    /// Modifies a download, e.g. to simulate its progress or completion.
+    pub fn update_download(
+        &self,
+        download_id: DownloadId,
+        update: impl FnOnce(&mut DownloadItem),
+    ) -> Result<(), Error> {
+        update(self.state.borrow_mut().download_mut(download_id)?);
+        Ok(())
+    }
+}
+
+impl Default for FakeBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn error(message: impl Into<String>) -> Error {
+    Error::Backend(message.into())
+}
+
+/// Matches `text` against a pattern in which `*` matches any sequence of characters.
+///
+/// This approximates the match patterns of the browser, e.g. `https://*.example.com/*`.
+fn matches_pattern(pattern: &str, text: &str) -> bool {
+    let Some((prefix, rest)) = pattern.split_once('*') else {
+        return pattern == text;
+    };
+    let Some(mut text) = text.strip_prefix(prefix) else {
+        return false;
+    };
+    let (middle, suffix) = match rest.rsplit_once('*') {
+        Some((middle, suffix)) => (middle, suffix),
+        None => ("", rest),
+    };
+    if text.len() < suffix.len() || !text.ends_with(suffix) {
+        return false;
+    }
+    text = &text[..text.len() - suffix.len()];
+    for part in middle.split('*').filter(|part| !part.is_empty()) {
+        match text.find(part) {
+            Some(position) => text = &text[position + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+/// Returns `true` if every whitespace separated word of `query`
+/// is contained in one of the `fields`, ignoring case.
+fn matches_words<'a>(query: &str, fields: impl IntoIterator<Item = &'a str> + Clone) -> bool {
+    query.split_whitespace().all(|word| {
+        let word = word.to_lowercase();
+        fields
+            .clone()
+            .into_iter()
+            .any(|field| field.to_lowercase().contains(&word))
+    })
+}
@@ -0,0 +1,479 @@
+use super::{error, matches_pattern, FakeBackend, FakeWindow, State};
+use crate::{
+    backend::{TabsBackend, WindowsBackend},
+    tabs::{
+        CreateProperties, MoveProperties, QueryDetails, ReloadProperties, Status, Tab, TabId,
+        UpdateProperties, WindowType,
+    },
+    windows::{CreateData, CreateType, QueryOptions, UpdateInfo, Window, WindowId, WindowState},
+    Error,
+};
+
+const NEW_TAB_URL: &str = "chrome://newtab/";
+
+impl FakeWindow {
+    fn id(&self) -> WindowId {
+        self.window.id.expect("fake windows have an ID")
+    }
+
+    fn to_window(&self, options: &QueryOptions<'_>) -> Window {
+        Window {
+            tabs: options
+                .populate
+                .unwrap_or_default()
+                .then(|| self.tabs.clone()),
+            ..self.window.clone()
+        }
+    }
+
+    /// Updates the index and window of the tabs after they were inserted, moved or removed.
+    fn reindex(&mut self) {
+        let window_id = self.id();
+        for (index, tab) in self.tabs.iter_mut().enumerate() {
+            tab.index = index as u32;
+            tab.window_id = window_id;
+        }
+    }
+
+    fn activate(&mut self, position: usize) {
+        for (i, tab) in self.tabs.iter_mut().enumerate() {
+            tab.active = i == position;
+            tab.highlighted = i == position;
+        }
+    }
+
+    /// Activates a tab next to `position` if the active tab has been removed.
+    fn ensure_active(&mut self, position: usize) {
+        if !self.tabs.is_empty() && !self.tabs.iter().any(|tab| tab.active) {
+            self.activate(position.min(self.tabs.len() - 1));
+        }
+    }
+}
+
+impl State {
+    fn next_id(&mut self) -> i32 {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    fn current_window_id(&self) -> Option<WindowId> {
+        self.last_focused
+    }
+
+    fn resolve_window_id(&self, window_id: WindowId) -> Result<WindowId, Error> {
+        if window_id == WindowId::CURRENT {
+            self.current_window_id()
+                .ok_or_else(|| error("No current window."))
+        } else {
+            Ok(window_id)
+        }
+    }
+
+    fn window_position(&self, window_id: WindowId) -> Result<usize, Error> {
+        let window_id = self.resolve_window_id(window_id)?;
+        self.windows
+            .iter()
+            .position(|window| window.id() == window_id)
+            .ok_or_else(|| error(format!("No window with id: {}.", window_id.0)))
+    }
+
+    /// Returns the position of the window and the index of the tab.
+    fn tab_position(&self, tab_id: TabId) -> Result<(usize, usize), Error> {
+        self.windows
+            .iter()
+            .enumerate()
+            .find_map(|(window_position, window)| {
+                let index = window.tabs.iter().position(|tab| tab.id == Some(tab_id))?;
+                Some((window_position, index))
+            })
+            .ok_or_else(|| error(format!("No tab with id: {}.", tab_id.0)))
+    }
+
+    fn tab(&self, tab_id: TabId) -> Result<&Tab, Error> {
+        let (window_position, index) = self.tab_position(tab_id)?;
+        Ok(&self.windows[window_position].tabs[index])
+    }
+
+    fn focus(&mut self, window_position: usize) {
+        for (i, window) in self.windows.iter_mut().enumerate() {
+            window.window.focused = i == window_position;
+        }
+        self.last_focused = Some(self.windows[window_position].id());
+    }
+
+    fn new_tab(&mut self, window: &Window, url: &str) -> Tab {
+        Tab {
+            active: false,
+            audible: Some(false),
+            auto_discardable: true,
+            discarded: false,
+            fav_icon_url: None,
+            group_id: -1,
+            height: window.height,
+            highlighted: false,
+            id: Some(TabId(self.next_id())),
+            incognito: window.incognito,
+            index: 0,
+            opener_tab_id: None,
+            pending_url: None,
+            pinned: false,
+            session_id: None,
+            status: Some(Status::Complete),
+            title: Some(url.to_owned()),
+            url: Some(url.to_owned()),
+            width: window.width,
+            window_id: window.id.expect("fake windows have an ID"),
+        }
+    }
+
+    /// Inserts a tab at `index`, or at the end if `index` is `None` or out of bounds.
+    fn insert_tab(&mut self, window_position: usize, index: Option<usize>, tab: Tab) -> usize {
+        let window = &mut self.windows[window_position];
+        let index = index.map_or(window.tabs.len(), |index| index.min(window.tabs.len()));
+        window.tabs.insert(index, tab);
+        window.reindex();
+        index
+    }
+
+    /// Removes a tab from its window, but keeps the window even if it is empty.
+    fn detach_tab(&mut self, tab_id: TabId) -> Result<(usize, Tab), Error> {
+        let (window_position, index) = self.tab_position(tab_id)?;
+        let window = &mut self.windows[window_position];
+        let mut tab = window.tabs.remove(index);
+        window.reindex();
+        window.ensure_active(index);
+        tab.active = false;
+        tab.highlighted = false;
+        Ok((window_position, tab))
+    }
+
+    /// Closes windows without tabs, like the browser does.
+    fn remove_empty_windows(&mut self) {
+        self.windows.retain(|window| !window.tabs.is_empty());
+        if !self
+            .windows
+            .iter()
+            .any(|window| Some(window.id()) == self.last_focused)
+        {
+            self.last_focused = None;
+            if !self.windows.is_empty() {
+                self.focus(self.windows.len() - 1);
+            }
+        }
+    }
+
+    fn matches(&self, window: &FakeWindow, tab: &Tab, details: &QueryDetails<'_>) -> bool {
+        fn eq<T: PartialEq>(expected: Option<T>, actual: T) -> bool {
+            expected.map_or(true, |expected| expected == actual)
+        }
+        let is_current = Some(window.id()) == self.current_window_id();
+        let window_id = match details.window_id {
+            Some(WindowId::CURRENT) => self.current_window_id(),
+            window_id => window_id,
+        };
+        eq(details.active, tab.active)
+            && eq(details.audible, tab.audible.unwrap_or_default())
+            && eq(details.auto_discardable, tab.auto_discardable)
+            && eq(details.current_window, is_current)
+            && eq(details.discarded, tab.discarded)
+            // Tabs of the fake are never hidden or muted.
+            && eq(details.hidden, false)
+            && eq(details.highlighted, tab.highlighted)
+            && eq(details.index, tab.index as i32)
+            && eq(details.muted, false)
+            && eq(details.last_focused_window, is_current)
+            && eq(details.pinned, tab.pinned)
+            && eq(details.status, tab.status.unwrap_or(Status::Complete))
+            && details.title.map_or(true, |pattern| {
+                matches_pattern(pattern, tab.title.as_deref().unwrap_or_default())
+            })
+            && details.url.map_or(true, |pattern| {
+                matches_pattern(pattern, tab.url.as_deref().unwrap_or_default())
+            })
+            && (details.window_id.is_none() || window_id == Some(tab.window_id))
+            && eq(details.window_type.map(Some), window.window.type_)
+    }
+
+    fn create_window(&mut self, data: &CreateData<'_>) -> Result<usize, Error> {
+        if let Some(tab_id) = data.tab_id {
+            self.tab_position(tab_id)?;
+        }
+        let window = Window {
+            always_on_top: false,
+            focused: false,
+            height: data.height,
+            id: Some(WindowId(self.next_id())),
+            incognito: data.incognito.unwrap_or_default(),
+            left: data.left,
+            session_id: None,
+            state: Some(data.state.unwrap_or(WindowState::Normal)),
+            tabs: None,
+            title: None,
+            top: data.top,
+            type_: Some(match data.type_.unwrap_or(CreateType::Normal) {
+                CreateType::Normal => WindowType::Normal,
+                CreateType::Popup => WindowType::Popup,
+                CreateType::Panel => WindowType::Panel,
+            }),
+            width: data.width,
+        };
+        self.windows.push(FakeWindow {
+            window: window.clone(),
+            tabs: vec![],
+        });
+        let window_position = self.windows.len() - 1;
+        if let Some(tab_id) = data.tab_id {
+            let (_, tab) = self.detach_tab(tab_id)?;
+            self.insert_tab(window_position, None, tab);
+        }
+        let mut urls = data.url.clone().unwrap_or_default();
+        if urls.is_empty() && data.tab_id.is_none() {
+            urls.push(NEW_TAB_URL);
+        }
+        for url in urls {
+            let tab = self.new_tab(&window, url);
+            self.insert_tab(window_position, None, tab);
+        }
+        self.windows[window_position].ensure_active(0);
+        self.remove_empty_windows();
+        // Removing the window of the moved tab shifts the new window.
+        let window_position = self.window_position(window.id.unwrap())?;
+        if data.focused.unwrap_or(true) || self.last_focused.is_none() {
+            self.focus(window_position);
+        }
+        Ok(window_position)
+    }
+}
+
+impl TabsBackend for FakeBackend {
+    async fn get_tab(&self, tab_id: TabId) -> Result<Tab, Error> {
+        self.state.borrow().tab(tab_id).cloned()
+    }
+
+    async fn query_tabs(&self, details: &QueryDetails<'_>) -> Result<Vec<Tab>, Error> {
+        let state = self.state.borrow();
+        Ok(state
+            .windows
+            .iter()
+            .flat_map(|window| window.tabs.iter().map(move |tab| (window, tab)))
+            .filter(|(window, tab)| state.matches(window, tab, details))
+            .map(|(_, tab)| tab.clone())
+            .collect())
+    }
+
+    /// Opens the tab at the end of the current window,
+    /// which is created if there is none.
+    async fn create_tab(&self, props: CreateProperties<'_>) -> Result<Tab, Error> {
+        let mut state = self.state.borrow_mut();
+        let Some(window_id) = state.current_window_id() else {
+            let window_position = state.create_window(&CreateData {
+                url: Some(vec![props.url]),
+                ..Default::default()
+            })?;
+            return Ok(state.windows[window_position].tabs[0].clone());
+        };
+        let window_position = state.window_position(window_id)?;
+        let window = state.windows[window_position].window.clone();
+        let tab = state.new_tab(&window, props.url);
+        let index = state.insert_tab(window_position, None, tab);
+        let window = &mut state.windows[window_position];
+        if props.active {
+            window.activate(index);
+        } else {
+            window.ensure_active(index);
+        }
+        Ok(window.tabs[index].clone())
+    }
+
+    async fn update_tab(&self, tab_id: TabId, props: &UpdateProperties<'_>) -> Result<Tab, Error> {
+        let mut state = self.state.borrow_mut();
+        if let Some(opener_tab_id) = props.opener_tab_id {
+            state.tab(opener_tab_id)?;
+        }
+        let (window_position, index) = state.tab_position(tab_id)?;
+        let window = &mut state.windows[window_position];
+        if props.active == Some(true) {
+            window.activate(index);
+        }
+        let tab = &mut window.tabs[index];
+        if let Some(auto_discardable) = props.auto_discardable {
+            tab.auto_discardable = auto_discardable;
+        }
+        if let Some(highlighted) = props.highlighted {
+            tab.highlighted = highlighted || tab.active;
+        }
+        if let Some(opener_tab_id) = props.opener_tab_id {
+            tab.opener_tab_id = Some(opener_tab_id);
+        }
+        if let Some(pinned) = props.pinned {
+            tab.pinned = pinned;
+        }
+        if let Some(url) = props.url {
+            tab.url = Some(url.to_owned());
+            tab.title = Some(url.to_owned());
+            tab.status = Some(Status::Complete);
+        }
+        Ok(tab.clone())
+    }
+
+    async fn remove_tabs(&self, tab_ids: &[TabId]) -> Result<(), Error> {
+        let mut state = self.state.borrow_mut();
+        for &tab_id in tab_ids {
+            state.tab(tab_id)?;
+        }
+        for &tab_id in tab_ids {
+            state.detach_tab(tab_id)?;
+        }
+        state.remove_empty_windows();
+        Ok(())
+    }
+
+    async fn reload_tab(&self, tab_id: TabId, _: ReloadProperties) -> Result<(), Error> {
+        let mut state = self.state.borrow_mut();
+        let (window_position, index) = state.tab_position(tab_id)?;
+        state.windows[window_position].tabs[index].status = Some(Status::Complete);
+        Ok(())
+    }
+
+    /// Opens a copy of the tab next to it and activates the copy.
+    async fn duplicate_tab(&self, tab_id: TabId) -> Result<Tab, Error> {
+        let mut state = self.state.borrow_mut();
+        let (window_position, index) = state.tab_position(tab_id)?;
+        let tab = Tab {
+            id: Some(TabId(state.next_id())),
+            opener_tab_id: Some(tab_id),
+            ..state.windows[window_position].tabs[index].clone()
+        };
+        let index = state.insert_tab(window_position, Some(index + 1), tab);
+        let window = &mut state.windows[window_position];
+        window.activate(index);
+        Ok(window.tabs[index].clone())
+    }
+
+    async fn move_tabs(&self, tab_ids: &[TabId], props: MoveProperties) -> Result<Vec<Tab>, Error> {
+        let mut state = self.state.borrow_mut();
+        for &tab_id in tab_ids {
+            state.tab(tab_id)?;
+        }
+        let target_window_id = match props.window_id {
+            Some(window_id) => Some(state.resolve_window_id(window_id)?),
+            None => None,
+        };
+        if let Some(window_id) = target_window_id {
+            state.window_position(window_id)?;
+        }
+        for (i, &tab_id) in tab_ids.iter().enumerate() {
+            let (window_position, tab) = state.detach_tab(tab_id)?;
+            let window_position = match target_window_id {
+                Some(window_id) => state.window_position(window_id)?,
+                None => window_position,
+            };
+            let index = usize::try_from(props.index).ok().map(|index| index + i);
+            let index = state.insert_tab(window_position, index, tab);
+            state.windows[window_position].ensure_active(index);
+        }
+        state.remove_empty_windows();
+        tab_ids
+            .iter()
+            .map(|&tab_id| state.tab(tab_id).cloned())
+            .collect()
+    }
+}
+
+impl WindowsBackend for FakeBackend {
+    async fn get_window(
+        &self,
+        window_id: WindowId,
+        options: &QueryOptions<'_>,
+    ) -> Result<Window, Error> {
+        let state = self.state.borrow();
+        let window_position = state.window_position(window_id)?;
+        Ok(state.windows[window_position].to_window(options))
+    }
+
+    /// Returns only normal windows and popups, unless [`QueryOptions::window_types`] is set.
+    async fn get_all_windows(&self, options: &QueryOptions<'_>) -> Result<Vec<Window>, Error> {
+        let window_types = options
+            .window_types
+            .unwrap_or(&[WindowType::Normal, WindowType::Popup]);
+        Ok(self
+            .state
+            .borrow()
+            .windows
+            .iter()
+            .filter(|window| {
+                window
+                    .window
+                    .type_
+                    .is_some_and(|type_| window_types.contains(&type_))
+            })
+            .map(|window| window.to_window(options))
+            .collect())
+    }
+
+    async fn get_current_window(&self, options: &QueryOptions<'_>) -> Result<Window, Error> {
+        self.get_window(WindowId::CURRENT, options).await
+    }
+
+    async fn get_last_focused_window(&self, options: &QueryOptions<'_>) -> Result<Window, Error> {
+        self.get_window(WindowId::CURRENT, options).await
+    }
+
+    /// Opens the window with a new tab page, unless [`CreateData::url`]
+    /// or [`CreateData::tab_id`] is set.
+    async fn create_window(&self, data: &CreateData<'_>) -> Result<Window, Error> {
+        let mut state = self.state.borrow_mut();
+        let window_position = state.create_window(data)?;
+        Ok(state.windows[window_position].to_window(&QueryOptions {
+            populate: Some(true),
+            ..Default::default()
+        }))
+    }
+
+    async fn update_window(&self, window_id: WindowId, info: &UpdateInfo) -> Result<Window, Error> {
+        let mut state = self.state.borrow_mut();
+        let window_position = state.window_position(window_id)?;
+        match info.focused {
+            Some(true) => state.focus(window_position),
+            Some(false) => {
+                state.windows[window_position].window.focused = false;
+                // Like in the browser, another window gets the focus,
+                // a single window remains the last focused one.
+                let id = state.windows[window_position].id();
+                let other = (0..state.windows.len())
+                    .rev()
+                    .find(|i| *i != window_position);
+                if let (Some(other), true) = (other, state.last_focused == Some(id)) {
+                    state.focus(other);
+                }
+            }
+            None => {}
+        }
+        let window = &mut state.windows[window_position].window;
+        if let Some(height) = info.height {
+            window.height = Some(height);
+        }
+        if let Some(left) = info.left {
+            window.left = Some(left);
+        }
+        if let Some(state) = info.state {
+            window.state = Some(state);
+        }
+        if let Some(top) = info.top {
+            window.top = Some(top);
+        }
+        if let Some(width) = info.width {
+            window.width = Some(width);
+        }
+        Ok(window.clone())
+    }
+
+    async fn remove_window(&self, window_id: WindowId) -> Result<(), Error> {
+        let mut state = self.state.borrow_mut();
+        let window_position = state.window_position(window_id)?;
+        state.windows[window_position].tabs.clear();
+        state.remove_empty_windows();
+        Ok(())
+    }
+}
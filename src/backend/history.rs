@@ -0,0 +1,53 @@
+use super::BrowserBackend;
+use crate::{
+    history::{self, HistoryItem, Query, VisitItem},
+    Error, Timestamp,
+};
+
+/// The operations of the [`history`](crate::history) API.
+#[allow(async_fn_in_trait)]
+pub trait HistoryBackend {
+    /// See [`history::search`].
+    async fn search_history(&self, query: &Query<'_>) -> Result<Vec<HistoryItem>, Error>;
+
+    /// See [`history::get_visits`].
+    async fn get_visits(&self, url: &str) -> Result<Vec<VisitItem>, Error>;
+
+    /// See [`history::add_url`].
+    async fn add_url(&self, url: &str) -> Result<(), Error>;
+
+    /// See [`history::delete_url`].
+    async fn delete_url(&self, url: &str) -> Result<(), Error>;
+
+    /// See [`history::delete_range`].
+    async fn delete_range(&self, start_time: Timestamp, end_time: Timestamp) -> Result<(), Error>;
+
+    /// See [`history::delete_all`].
+    async fn delete_all_history(&self) -> Result<(), Error>;
+}
+
+impl HistoryBackend for BrowserBackend {
+    async fn search_history(&self, query: &Query<'_>) -> Result<Vec<HistoryItem>, Error> {
+        history::search(query).await
+    }
+
+    async fn get_visits(&self, url: &str) -> Result<Vec<VisitItem>, Error> {
+        history::get_visits(url).await
+    }
+
+    async fn add_url(&self, url: &str) -> Result<(), Error> {
+        history::add_url(url).await
+    }
+
+    async fn delete_url(&self, url: &str) -> Result<(), Error> {
+        history::delete_url(url).await
+    }
+
+    async fn delete_range(&self, start_time: Timestamp, end_time: Timestamp) -> Result<(), Error> {
+        history::delete_range(start_time, end_time).await
+    }
+
+    async fn delete_all_history(&self) -> Result<(), Error> {
+        history::delete_all().await
+    }
+}
@@ -0,0 +1,40 @@
+//! Abstraction over the browser APIs, so that extension logic can be tested natively.
+//!
+//! Code that is generic over the traits of this module runs with the
+//! [`BrowserBackend`] in the extension and with the in-memory [`FakeBackend`]
+//! under a plain `cargo test`:
+//!
+//! ```
+//! use web_extensions::{backend::HistoryBackend, Error};
+//!
+//! async fn was_visited(backend: &impl HistoryBackend, url: &str) -> Result<bool, Error> {
+//!     Ok(!backend.get_visits(url).await?.is_empty())
+//! }
+//! ```
+//!
+//! The futures of the traits are not `Send`,
+//! which is not required on the single-threaded wasm target.
+
+mod bookmarks;
+mod downloads;
+mod fake;
+mod history;
+mod tabs;
+mod windows;
+
+pub use self::{bookmarks::*, downloads::*, fake::*, history::*, tabs::*, windows::*};
+
+/// All APIs that are available through a backend.
+pub trait Backend:
+    TabsBackend + WindowsBackend + BookmarksBackend + HistoryBackend + DownloadsBackend
+{
+}
+
+impl<T> Backend for T where
+    T: TabsBackend + WindowsBackend + BookmarksBackend + HistoryBackend + DownloadsBackend
+{
+}
+
+/// The backend that calls the APIs of the [detected](crate::Browser::detect) browser.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BrowserBackend;
@@ -0,0 +1,70 @@
+use super::BrowserBackend;
+use crate::{
+    tabs::{
+        self, CreateProperties, MoveProperties, QueryDetails, ReloadProperties, Tab, TabId,
+        UpdateProperties,
+    },
+    Error,
+};
+
+/// The operations of the [`tabs`](crate::tabs) API.
+#[allow(async_fn_in_trait)]
+pub trait TabsBackend {
+    /// See [`tabs::get`].
+    async fn get_tab(&self, tab_id: TabId) -> Result<Tab, Error>;
+
+    /// See [`tabs::query`].
+    async fn query_tabs(&self, details: &QueryDetails<'_>) -> Result<Vec<Tab>, Error>;
+
+    /// See [`tabs::create`].
+    async fn create_tab(&self, props: CreateProperties<'_>) -> Result<Tab, Error>;
+
+    /// See [`tabs::update`].
+    async fn update_tab(&self, tab_id: TabId, props: &UpdateProperties<'_>) -> Result<Tab, Error>;
+
+    /// See [`tabs::remove`].
+    async fn remove_tabs(&self, tab_ids: &[TabId]) -> Result<(), Error>;
+
+    /// See [`tabs::reload`].
+    async fn reload_tab(&self, tab_id: TabId, props: ReloadProperties) -> Result<(), Error>;
+
+    /// See [`tabs::duplicate`].
+    async fn duplicate_tab(&self, tab_id: TabId) -> Result<Tab, Error>;
+
+    /// See [`tabs::move_tabs`].
+    async fn move_tabs(&self, tab_ids: &[TabId], props: MoveProperties) -> Result<Vec<Tab>, Error>;
+}
+
+impl TabsBackend for BrowserBackend {
+    async fn get_tab(&self, tab_id: TabId) -> Result<Tab, Error> {
+        tabs::get(tab_id).await
+    }
+
+    async fn query_tabs(&self, details: &QueryDetails<'_>) -> Result<Vec<Tab>, Error> {
+        tabs::query(details).await
+    }
+
+    async fn create_tab(&self, props: CreateProperties<'_>) -> Result<Tab, Error> {
+        tabs::create(props).await
+    }
+
+    async fn update_tab(&self, tab_id: TabId, props: &UpdateProperties<'_>) -> Result<Tab, Error> {
+        tabs::update(tab_id, props).await
+    }
+
+    async fn remove_tabs(&self, tab_ids: &[TabId]) -> Result<(), Error> {
+        tabs::remove(tab_ids).await
+    }
+
+    async fn reload_tab(&self, tab_id: TabId, props: ReloadProperties) -> Result<(), Error> {
+        tabs::reload(tab_id, props).await
+    }
+
+    async fn duplicate_tab(&self, tab_id: TabId) -> Result<Tab, Error> {
+        tabs::duplicate(tab_id).await
+    }
+
+    async fn move_tabs(&self, tab_ids: &[TabId], props: MoveProperties) -> Result<Vec<Tab>, Error> {
+        tabs::move_tabs(tab_ids, props).await
+    }
+}
@@ -0,0 +1,68 @@
+use super::BrowserBackend;
+use crate::{
+    windows::{self, CreateData, QueryOptions, UpdateInfo, Window, WindowId},
+    Error,
+};
+
+/// The operations of the [`windows`](crate::windows) API.
+#[allow(async_fn_in_trait)]
+pub trait WindowsBackend {
+    /// See [`windows::get`].
+    async fn get_window(
+        &self,
+        window_id: WindowId,
+        options: &QueryOptions<'_>,
+    ) -> Result<Window, Error>;
+
+    /// See [`windows::get_all`].
+    async fn get_all_windows(&self, options: &QueryOptions<'_>) -> Result<Vec<Window>, Error>;
+
+    /// See [`windows::get_current`].
+    async fn get_current_window(&self, options: &QueryOptions<'_>) -> Result<Window, Error>;
+
+    /// See [`windows::get_last_focused`].
+    async fn get_last_focused_window(&self, options: &QueryOptions<'_>) -> Result<Window, Error>;
+
+    /// See [`windows::create`].
+    async fn create_window(&self, data: &CreateData<'_>) -> Result<Window, Error>;
+
+    /// See [`windows::update`].
+    async fn update_window(&self, window_id: WindowId, info: &UpdateInfo) -> Result<Window, Error>;
+
+    /// See [`windows::remove`].
+    async fn remove_window(&self, window_id: WindowId) -> Result<(), Error>;
+}
+
+impl WindowsBackend for BrowserBackend {
+    async fn get_window(
+        &self,
+        window_id: WindowId,
+        options: &QueryOptions<'_>,
+    ) -> Result<Window, Error> {
+        windows::get(window_id, options).await
+    }
+
+    async fn get_all_windows(&self, options: &QueryOptions<'_>) -> Result<Vec<Window>, Error> {
+        windows::get_all(options).await
+    }
+
+    async fn get_current_window(&self, options: &QueryOptions<'_>) -> Result<Window, Error> {
+        windows::get_current(options).await
+    }
+
+    async fn get_last_focused_window(&self, options: &QueryOptions<'_>) -> Result<Window, Error> {
+        windows::get_last_focused(options).await
+    }
+
+    async fn create_window(&self, data: &CreateData<'_>) -> Result<Window, Error> {
+        windows::create(data).await
+    }
+
+    async fn update_window(&self, window_id: WindowId, info: &UpdateInfo) -> Result<Window, Error> {
+        windows::update(window_id, info).await
+    }
+
+    async fn remove_window(&self, window_id: WindowId) -> Result<(), Error> {
+        windows::remove(window_id).await
+    }
+}
@@ -0,0 +1,70 @@
+use super::{prelude::*, BookmarkTreeNode};
+
+/// The version of the JSON format written by [`to_json`].
+const JSON_FORMAT_VERSION: u32 = 1;
+
+/// A bookmark or folder without any browser specific IDs,
+/// e.g. for exporting and importing bookmarks.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BookmarkEntry {
+    pub title: String,
+
+    /// The URL of the bookmark. Folders have no URL.
+    pub url: Option<String>,
+
+    /// When the node was created.
+    pub date_added: Option<Timestamp>,
+
+    /// When the contents of this folder last changed.
+    pub last_modified: Option<Timestamp>,
+
+    /// The ordered children of a folder.
+    pub children: Vec<Self>,
+}
+
+impl BookmarkEntry {
+    pub fn is_folder(&self) -> bool {
+        self.url.is_none()
+    }
+}
+
+impl From<&BookmarkTreeNode> for BookmarkEntry {
+    fn from(node: &BookmarkTreeNode) -> Self {
+        Self {
+            title: node.title.clone(),
+            url: node.url.clone(),
+            date_added: node.date_added,
+            last_modified: node.date_group_modified,
+            children: node.child_nodes().iter().map(Self::from).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct JsonFile<T> {
+    version: u32,
+    bookmarks: T,
+}
+
+/// Serializes the entries into a versioned JSON document.
+pub fn to_json(entries: &[BookmarkEntry]) -> Result<String, Error> {
+    serde_json::to_string(&JsonFile {
+        version: JSON_FORMAT_VERSION,
+        bookmarks: entries,
+    })
+    .map_err(Error::JsonSerialization)
+}
+
+/// Parses a JSON document written by [`to_json`].
+pub fn from_json(json: &str) -> Result<Vec<BookmarkEntry>, Error> {
+    let file: JsonFile<Vec<BookmarkEntry>> =
+        serde_json::from_str(json).map_err(Error::JsonDeserialization)?;
+    if file.version > JSON_FORMAT_VERSION {
+        return Err(Error::UnsupportedSchemaVersion {
+            found: file.version,
+            supported: JSON_FORMAT_VERSION,
+        });
+    }
+    Ok(file.bookmarks)
+}
@@ -0,0 +1,34 @@
+use super::prelude::*;
+
+/// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#type-BookmarkTreeNode>
+///
+/// A node (either a bookmark or a folder) in the bookmark tree.
+/// Child nodes are ordered within their parent folder.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BookmarkTreeNode {
+    /// Unique identifier.
+    pub id: BookmarkId,
+
+    /// An ordered list of children of this node.
+    pub children: Option<Vec<Self>>,
+
+    /// The 0-based position of this node within its parent folder.
+    pub index: Option<u32>,
+
+    /// The ID of the parent folder. This property is
+    /// not present in the root node.
+    pub parent_id: Option<BookmarkId>,
+
+    /// Date and time of the creation of the bookmark.
+    pub date_added: Option<Timestamp>,
+
+    /// When the contents of this folder last changed.
+    pub date_group_modified: Option<Timestamp>,
+
+    /// The text displayed for the node in menus and lists of bookmarks.
+    pub title: String,
+
+    /// The URL for the bookmark. Empty if this node is a Folder.
+    pub url: Option<String>,
+}
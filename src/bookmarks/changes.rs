@@ -0,0 +1,14 @@
+use super::prelude::*;
+
+/// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#method-update>
+///
+/// The properties to change with [`update`](super::update).
+/// Properties that are not specified are left unchanged.
+///
+/// **Note:** Currently, only `title` and `url` are supported.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Changes<'a> {
+    pub title: Option<&'a str>,
+    pub url: Option<&'a str>,
+}
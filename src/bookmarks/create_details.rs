@@ -0,0 +1,21 @@
+use super::prelude::*;
+
+/// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#type-CreateDetails>
+///
+/// Object passed to [`create`](super::create).
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateDetails<'a> {
+    /// Defaults to the Other Bookmarks folder.
+    pub parent_id: Option<&'a BookmarkId>,
+
+    /// The 0-based position within the parent folder.
+    ///
+    /// Defaults to the end of the folder.
+    pub index: Option<u32>,
+
+    pub title: Option<&'a str>,
+
+    /// The URL of the bookmark. A folder is created if omitted.
+    pub url: Option<&'a str>,
+}
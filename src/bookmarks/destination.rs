@@ -0,0 +1,14 @@
+use super::prelude::*;
+
+/// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#method-move>
+///
+/// The new position of a node moved with [`move_node`](super::move_node).
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Destination<'a> {
+    /// Defaults to the current parent folder.
+    pub parent_id: Option<&'a BookmarkId>,
+
+    /// The 0-based position within the parent folder.
+    pub index: Option<u32>,
+}
@@ -0,0 +1,194 @@
+use super::{prelude::*, BookmarkTreeNode, Changes, CreateDetails, Destination};
+use crate::backend::{BookmarksBackend, BrowserBackend};
+use std::collections::{HashMap, HashSet};
+
+/// A single change of the bookmark tree as computed by [`diff`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Operation {
+    /// Create a bookmark or, without URL, a folder.
+    ///
+    /// The `id` is the ID of the node in the new tree,
+    /// the browser assigns a different ID to the created node.
+    Create {
+        id: BookmarkId,
+        parent_id: BookmarkId,
+        index: u32,
+        title: String,
+        url: Option<String>,
+    },
+    Move {
+        id: BookmarkId,
+        parent_id: BookmarkId,
+        index: u32,
+    },
+    Update {
+        id: BookmarkId,
+        title: String,
+        url: Option<String>,
+    },
+    /// Remove a node, folders are removed together with their remaining children.
+    Remove { id: BookmarkId, recursive: bool },
+}
+
+/// Computes the operations that turn the tree `old` into the tree `new`.
+///
+/// Nodes are matched by their ID, both trees must have the same root node
+/// which is never changed.
+/// The operations must be applied in order, e.g. with [`apply_diff`].
+pub fn diff(old: &BookmarkTreeNode, new: &BookmarkTreeNode) -> Vec<Operation> {
+    let old_nodes: HashMap<_, _> = old
+        .iter_depth_first()
+        .map(|node| (&node.id, node))
+        .collect();
+    let new_ids: HashSet<_> = new.iter_depth_first().map(|node| &node.id).collect();
+
+    // The children of every folder and the parent of every node
+    // while the operations are applied one after another.
+    let mut children: HashMap<_, Vec<_>> = old
+        .iter_depth_first()
+        .filter(|node| node.is_folder())
+        .map(|node| (&node.id, node.child_nodes().iter().map(|c| &c.id).collect()))
+        .collect();
+    let mut parents: HashMap<_, _> = old
+        .iter_depth_first()
+        .flat_map(|node| node.child_nodes().iter().map(move |c| (&c.id, &node.id)))
+        .collect();
+
+    let mut operations = Vec::new();
+    let mut updates = Vec::new();
+    let mut folders = vec![new];
+    while let Some(folder) = folders.pop() {
+        for (index, child) in folder.child_nodes().iter().enumerate() {
+            match old_nodes.get(&child.id) {
+                None => {
+                    operations.push(Operation::Create {
+                        id: child.id.clone(),
+                        parent_id: folder.id.clone(),
+                        index: index as u32,
+                        title: child.title.clone(),
+                        url: child.url.clone(),
+                    });
+                    children.entry(&child.id).or_default();
+                    children
+                        .entry(&folder.id)
+                        .or_default()
+                        .insert(index, &child.id);
+                    parents.insert(&child.id, &folder.id);
+                }
+                Some(old_child) => {
+                    let parent = parents[&child.id];
+                    let position = children[parent].iter().position(|id| *id == &child.id);
+                    if parent != &folder.id || position != Some(index) {
+                        operations.push(Operation::Move {
+                            id: child.id.clone(),
+                            parent_id: folder.id.clone(),
+                            index: index as u32,
+                        });
+                        children
+                            .entry(parent)
+                            .or_default()
+                            .retain(|id| *id != &child.id);
+                        children
+                            .entry(&folder.id)
+                            .or_default()
+                            .insert(index, &child.id);
+                        parents.insert(&child.id, &folder.id);
+                    }
+                    if old_child.title != child.title || old_child.url != child.url {
+                        updates.push(Operation::Update {
+                            id: child.id.clone(),
+                            title: child.title.clone(),
+                            url: child.url.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        folders.extend(folder.child_nodes().iter().rev());
+    }
+    operations.append(&mut updates);
+
+    let mut removed = vec![old];
+    while let Some(node) = removed.pop() {
+        for child in node.child_nodes() {
+            if new_ids.contains(&child.id) {
+                removed.push(child);
+            } else {
+                operations.push(Operation::Remove {
+                    id: child.id.clone(),
+                    recursive: child.is_folder(),
+                });
+            }
+        }
+    }
+    operations
+}
+
+/// Applies the operations returned by [`diff`] with the bookmarks API.
+pub async fn apply_diff(operations: &[Operation]) -> Result<(), Error> {
+    apply_diff_with(&BrowserBackend, operations).await
+}
+
+/// Like [`apply_diff`], but changes the bookmarks of `backend`.
+pub async fn apply_diff_with(
+    backend: &impl BookmarksBackend,
+    operations: &[Operation],
+) -> Result<(), Error> {
+    // The IDs of the created nodes by their IDs in the new tree.
+    let mut created = HashMap::<&BookmarkId, BookmarkId>::new();
+    for operation in operations {
+        match operation {
+            Operation::Create {
+                id,
+                parent_id,
+                index,
+                title,
+                url,
+            } => {
+                let node = backend
+                    .create_bookmark(&CreateDetails {
+                        parent_id: Some(created.get(parent_id).unwrap_or(parent_id)),
+                        index: Some(*index),
+                        title: Some(title),
+                        url: url.as_deref(),
+                    })
+                    .await?;
+                created.insert(id, node.id);
+            }
+            Operation::Move {
+                id,
+                parent_id,
+                index,
+            } => {
+                backend
+                    .move_bookmark(
+                        id,
+                        &Destination {
+                            parent_id: Some(created.get(parent_id).unwrap_or(parent_id)),
+                            index: Some(*index),
+                        },
+                    )
+                    .await?;
+            }
+            Operation::Update { id, title, url } => {
+                backend
+                    .update_bookmark(
+                        id,
+                        &Changes {
+                            title: Some(title),
+                            url: url.as_deref(),
+                        },
+                    )
+                    .await?;
+            }
+            Operation::Remove { id, recursive } => {
+                if *recursive {
+                    backend.remove_bookmark_tree(id).await?;
+                } else {
+                    backend.remove_bookmark(id).await?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,63 @@
+//! Bindings of the methods and events that `web_extensions_sys::Bookmarks` does not cover (yet).
+
+use js_sys::Object;
+use wasm_bindgen::prelude::*;
+use web_extensions_sys as sys;
+
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type Bookmarks;
+
+    #[wasm_bindgen(catch, method)]
+    pub(crate) async fn create(this: &Bookmarks, bookmark: &Object) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, method)]
+    pub(crate) async fn get(this: &Bookmarks, id_or_id_list: &JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, method, js_name = getChildren)]
+    pub(crate) async fn get_children(this: &Bookmarks, id: &str) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, method, js_name = getRecent)]
+    pub(crate) async fn get_recent(
+        this: &Bookmarks,
+        number_of_items: u32,
+    ) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, method, js_name = getSubTree)]
+    pub(crate) async fn get_sub_tree(this: &Bookmarks, id: &str) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, method, js_name = getTree)]
+    pub(crate) async fn get_tree(this: &Bookmarks) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, method, js_name = move)]
+    pub(crate) async fn move_node(
+        this: &Bookmarks,
+        id: &str,
+        destination: &Object,
+    ) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, method)]
+    pub(crate) async fn remove(this: &Bookmarks, id: &str) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, method, js_name = removeTree)]
+    pub(crate) async fn remove_tree(this: &Bookmarks, id: &str) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, method)]
+    pub(crate) async fn update(
+        this: &Bookmarks,
+        id: &str,
+        changes: &Object,
+    ) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(method, getter, js_name = onChanged)]
+    pub(crate) fn on_changed(this: &Bookmarks) -> sys::EventTarget;
+
+    #[wasm_bindgen(method, getter, js_name = onCreated)]
+    pub(crate) fn on_created(this: &Bookmarks) -> sys::EventTarget;
+
+    #[wasm_bindgen(method, getter, js_name = onMoved)]
+    pub(crate) fn on_moved(this: &Bookmarks) -> sys::EventTarget;
+
+    #[wasm_bindgen(method, getter, js_name = onRemoved)]
+    pub(crate) fn on_removed(this: &Bookmarks) -> sys::EventTarget;
+}
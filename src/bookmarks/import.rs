@@ -0,0 +1,50 @@
+use super::{prelude::*, BookmarkEntry, BookmarkTreeNode, CreateDetails};
+use crate::backend::{BookmarksBackend, BrowserBackend};
+
+/// Recreates the entries at the end of the folder `parent_id` with [`create`](super::create).
+///
+/// The browser assigns new creation dates.
+/// Returns the created top-level nodes.
+pub async fn import(
+    parent_id: &BookmarkId,
+    entries: &[BookmarkEntry],
+) -> Result<Vec<BookmarkTreeNode>, Error> {
+    import_with(&BrowserBackend, parent_id, entries).await
+}
+
+/// Like [`import`], but creates the bookmarks in `backend`.
+pub async fn import_with(
+    backend: &impl BookmarksBackend,
+    parent_id: &BookmarkId,
+    entries: &[BookmarkEntry],
+) -> Result<Vec<BookmarkTreeNode>, Error> {
+    let mut created = Vec::with_capacity(entries.len());
+    // Parents are created before their children,
+    // siblings in their order.
+    let mut pending: Vec<_> = entries
+        .iter()
+        .rev()
+        .map(|entry| (parent_id.clone(), entry))
+        .collect();
+    while let Some((entry_parent_id, entry)) = pending.pop() {
+        let node = backend
+            .create_bookmark(&CreateDetails {
+                parent_id: Some(&entry_parent_id),
+                index: None,
+                title: Some(&entry.title),
+                url: entry.url.as_deref(),
+            })
+            .await?;
+        pending.extend(
+            entry
+                .children
+                .iter()
+                .rev()
+                .map(|child| (node.id.clone(), child)),
+        );
+        if &entry_parent_id == parent_id {
+            created.push(node);
+        }
+    }
+    Ok(created)
+}
@@ -0,0 +1,163 @@
+//! Wrapper for the [`chrome.bookmarks` API](https://developer.chrome.com/docs/extensions/reference/bookmarks/).
+
+pub(crate) mod prelude {
+    pub(crate) use crate::event_stream::define_event;
+    pub(crate) use crate::util::{
+        js_from_serde, object_from_js, serde_from_js, serde_from_js_result,
+    };
+    pub use crate::{bookmarks::BookmarkId, Error, Timestamp};
+    pub use serde::{Deserialize, Serialize};
+    pub use web_extensions_sys as sys;
+
+    pub fn bookmarks() -> sys::Bookmarks {
+        crate::browser().api().bookmarks()
+    }
+
+    pub(crate) fn bookmarks_ffi() -> super::ffi::Bookmarks {
+        use wasm_bindgen::JsCast;
+        bookmarks().unchecked_into()
+    }
+}
+
+use self::prelude::*;
+use std::fmt;
+
+mod bookmark_entry;
+mod bookmark_tree_node;
+mod changes;
+mod create_details;
+mod destination;
+mod diff;
+mod ffi;
+mod import;
+mod netscape;
+mod on_changed;
+mod on_children_reordered;
+mod on_created;
+mod on_import_began;
+mod on_import_ended;
+mod on_moved;
+mod on_removed;
+mod query;
+mod tree;
+
+pub use self::{
+    bookmark_entry::*, bookmark_tree_node::*, changes::*, create_details::*, destination::*,
+    diff::*, import::*, netscape::*, on_changed::*, on_children_reordered::*, on_created::*,
+    on_import_began::*, on_import_ended::*, on_moved::*, on_removed::*, query::*, tree::*,
+};
+
+/// The ID of a bookmark or folder.
+///
+/// Bookmark IDs are unique within the current profile
+/// and remain valid even after the browser is restarted.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct BookmarkId(String);
+
+impl BookmarkId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for BookmarkId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+
+impl From<&str> for BookmarkId {
+    fn from(id: &str) -> Self {
+        Self(id.to_owned())
+    }
+}
+
+impl fmt::Display for BookmarkId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#method-get>
+pub async fn get(ids: &[BookmarkId]) -> Result<Vec<BookmarkTreeNode>, Error> {
+    let js_ids = js_from_serde(ids)?;
+    let result = bookmarks_ffi().get(&js_ids).await;
+    serde_from_js_result(result)
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#method-getChildren>
+pub async fn get_children(id: &BookmarkId) -> Result<Vec<BookmarkTreeNode>, Error> {
+    let result = bookmarks_ffi().get_children(id.as_str()).await;
+    serde_from_js_result(result)
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#method-getRecent>
+pub async fn get_recent(number_of_items: u32) -> Result<Vec<BookmarkTreeNode>, Error> {
+    let result = bookmarks_ffi().get_recent(number_of_items).await;
+    serde_from_js_result(result)
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#method-getTree>
+///
+/// Returns the root node, which contains the entire bookmark hierarchy.
+pub async fn get_tree() -> Result<Vec<BookmarkTreeNode>, Error> {
+    let result = bookmarks_ffi().get_tree().await;
+    serde_from_js_result(result)
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#method-getSubTree>
+pub async fn get_sub_tree(id: &BookmarkId) -> Result<Vec<BookmarkTreeNode>, Error> {
+    let result = bookmarks_ffi().get_sub_tree(id.as_str()).await;
+    serde_from_js_result(result)
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#method-search>
+pub async fn search(query: &Query<'_>) -> Result<Vec<BookmarkTreeNode>, Error> {
+    let js_query = js_from_serde(query)?;
+    let js_value = bookmarks().search(&js_query).await;
+    serde_from_js(js_value)
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#method-create>
+pub async fn create(details: &CreateDetails<'_>) -> Result<BookmarkTreeNode, Error> {
+    let js_details = js_from_serde(details)?;
+    let result = bookmarks_ffi().create(object_from_js(&js_details)?).await;
+    serde_from_js_result(result)
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#method-update>
+pub async fn update(id: &BookmarkId, changes: &Changes<'_>) -> Result<BookmarkTreeNode, Error> {
+    let js_changes = js_from_serde(changes)?;
+    let result = bookmarks_ffi()
+        .update(id.as_str(), object_from_js(&js_changes)?)
+        .await;
+    serde_from_js_result(result)
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#method-move>
+pub async fn move_node(
+    id: &BookmarkId,
+    destination: &Destination<'_>,
+) -> Result<BookmarkTreeNode, Error> {
+    let js_destination = js_from_serde(destination)?;
+    let result = bookmarks_ffi()
+        .move_node(id.as_str(), object_from_js(&js_destination)?)
+        .await;
+    serde_from_js_result(result)
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#method-remove>
+///
+/// Removes a bookmark or an empty folder.
+pub async fn remove(id: &BookmarkId) -> Result<(), Error> {
+    bookmarks_ffi().remove(id.as_str()).await?;
+    Ok(())
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#method-removeTree>
+///
+/// Recursively removes a folder.
+pub async fn remove_tree(id: &BookmarkId) -> Result<(), Error> {
+    bookmarks_ffi().remove_tree(id.as_str()).await?;
+    Ok(())
+}
@@ -0,0 +1,294 @@
+//! The Netscape Bookmark File format, i.e. the `bookmarks.html` that all browsers export.
+
+use super::{prelude::*, BookmarkEntry};
+use std::fmt::Write;
+
+const HEADER: &str = "<!DOCTYPE NETSCAPE-Bookmark-file-1>
+<!-- This is an automatically generated file.
+     It will be read and overwritten.
+     DO NOT EDIT! -->
+<META HTTP-EQUIV=\"Content-Type\" CONTENT=\"text/html; charset=UTF-8\">
+<TITLE>Bookmarks</TITLE>
+<H1>Bookmarks</H1>
+";
+
+/// Writes the entries as Netscape Bookmark File.
+///
+/// Dates are written in seconds since the epoch, as browsers expect them.
+pub fn to_netscape_html(entries: &[BookmarkEntry]) -> String {
+    let mut html = HEADER.to_owned();
+    write_list(&mut html, entries, 0);
+    html
+}
+
+fn write_list(html: &mut String, entries: &[BookmarkEntry], depth: usize) {
+    let indent = "    ".repeat(depth);
+    html.push_str(&indent);
+    html.push_str("<DL><p>\n");
+    for entry in entries {
+        html.push_str(&indent);
+        html.push_str("    <DT>");
+        let (tag, url) = match &entry.url {
+            Some(url) => ("A", Some(url)),
+            None => ("H3", None),
+        };
+        html.push('<');
+        html.push_str(tag);
+        if let Some(url) = url {
+            let _ = write!(html, " HREF=\"{}\"", escape(url));
+        }
+        if let Some(date_added) = entry.date_added {
+            let _ = write!(html, " ADD_DATE=\"{}\"", seconds(date_added));
+        }
+        if let Some(last_modified) = entry.last_modified {
+            let _ = write!(html, " LAST_MODIFIED=\"{}\"", seconds(last_modified));
+        }
+        let _ = writeln!(html, ">{}</{tag}>", escape(&entry.title));
+        if entry.is_folder() {
+            write_list(html, &entry.children, depth + 1);
+        }
+    }
+    html.push_str(&indent);
+    html.push_str("</DL><p>\n");
+}
+
+fn seconds(timestamp: Timestamp) -> i64 {
+    (timestamp.as_millis() / 1000.0).floor() as i64
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Parses a Netscape Bookmark File.
+///
+/// The parser is lenient and ignores everything except folders (`<H3>`),
+/// bookmarks (`<A>`) and lists (`<DL>`).
+pub fn from_netscape_html(html: &str) -> Result<Vec<BookmarkEntry>, Error> {
+    let mut parser = Parser::default();
+    let mut rest = html;
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+        } else if let Some(tag) = rest.strip_prefix('<') {
+            let end = tag_end(tag);
+            parser.tag(&tag[..end]);
+            rest = tag.get(end + 1..).unwrap_or_default();
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            parser.text(&rest[..end]);
+            rest = &rest[end..];
+        }
+    }
+    parser.finish()
+}
+
+/// Returns the position of the `>` that closes the tag, ignoring quoted attribute values.
+fn tag_end(tag: &str) -> usize {
+    let mut quote = None;
+    for (i, c) in tag.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            (None, '>') => return i,
+            _ => {}
+        }
+    }
+    tag.len()
+}
+
+#[derive(Default)]
+struct Parser {
+    /// The open lists with the folder they belong to, if any.
+    lists: Vec<(Option<BookmarkEntry>, Vec<BookmarkEntry>)>,
+    /// The top-level entries.
+    entries: Vec<BookmarkEntry>,
+    /// A folder whose list has not been opened yet.
+    folder: Option<BookmarkEntry>,
+    /// The entry whose title is being read.
+    title: Option<BookmarkEntry>,
+    found_list: bool,
+}
+
+impl Parser {
+    fn tag(&mut self, tag: &str) {
+        let (closing, tag) = match tag.strip_prefix('/') {
+            Some(tag) => (true, tag),
+            None => (false, tag),
+        };
+        let name_end = tag
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(tag.len());
+        let (name, attributes) = tag.split_at(name_end);
+        match (closing, name.to_ascii_uppercase().as_str()) {
+            (false, "DL") => {
+                self.found_list = true;
+                let folder = self.folder.take();
+                self.lists.push((folder, Vec::new()));
+            }
+            (true, "DL") => {
+                self.end_folder();
+                if let Some((folder, children)) = self.lists.pop() {
+                    match folder {
+                        Some(folder) => self.push(BookmarkEntry { children, ..folder }),
+                        // A list without heading, e.g. the top-level list.
+                        None => children.into_iter().for_each(|entry| self.push(entry)),
+                    }
+                }
+            }
+            (false, "H3") => {
+                self.end_folder();
+                self.title = Some(entry(attributes, None));
+            }
+            (false, "A") => {
+                self.end_folder();
+                let url = attribute(attributes, "HREF").unwrap_or_default();
+                self.title = Some(entry(attributes, Some(url)));
+            }
+            (true, "H3") => self.folder = self.title.take(),
+            (true, "A") => {
+                if let Some(bookmark) = self.title.take() {
+                    self.push(bookmark);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if let Some(entry) = &mut self.title {
+            entry.title.push_str(&decode(text));
+        }
+    }
+
+    /// Adds a folder that is not followed by a list.
+    fn end_folder(&mut self) {
+        if let Some(folder) = self.folder.take() {
+            self.push(folder);
+        }
+    }
+
+    fn push(&mut self, mut entry: BookmarkEntry) {
+        entry.title = entry.title.trim().to_owned();
+        match self.lists.last_mut() {
+            Some((_, children)) => children.push(entry),
+            None => self.entries.push(entry),
+        }
+    }
+
+    fn finish(mut self) -> Result<Vec<BookmarkEntry>, Error> {
+        if !self.found_list {
+            return Err(Error::InvalidBookmarkFile);
+        }
+        // Close unterminated lists.
+        while !self.lists.is_empty() {
+            self.tag("/DL");
+        }
+        self.end_folder();
+        Ok(self.entries)
+    }
+}
+
+fn entry(attributes: &str, url: Option<String>) -> BookmarkEntry {
+    let date = |name| {
+        attribute(attributes, name)
+            .and_then(|seconds| seconds.trim().parse::<i64>().ok())
+            .map(|seconds| Timestamp::from_millis(seconds as f64 * 1000.0))
+    };
+    BookmarkEntry {
+        title: String::new(),
+        url,
+        date_added: date("ADD_DATE"),
+        last_modified: date("LAST_MODIFIED"),
+        children: Vec::new(),
+    }
+}
+
+/// Returns the decoded value of an attribute, matching its name case-insensitively.
+fn attribute(attributes: &str, name: &str) -> Option<String> {
+    let mut rest = attributes.trim_start();
+    while !rest.is_empty() {
+        let name_end = rest
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let (attribute_name, after_name) = rest.split_at(name_end);
+        let after_name = after_name.trim_start();
+        let (value, after_value) = match after_name.strip_prefix('=') {
+            Some(value) => {
+                let value = value.trim_start();
+                match value.chars().next() {
+                    Some(quote @ ('"' | '\'')) => {
+                        let value = &value[1..];
+                        let end = value.find(quote).unwrap_or(value.len());
+                        (&value[..end], value.get(end + 1..).unwrap_or_default())
+                    }
+                    _ => {
+                        let end = value.find(char::is_whitespace).unwrap_or(value.len());
+                        value.split_at(end)
+                    }
+                }
+            }
+            None => ("", after_name),
+        };
+        if attribute_name.eq_ignore_ascii_case(name) {
+            return Some(decode(value));
+        }
+        rest = after_value.trim_start();
+    }
+    None
+}
+
+fn decode(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let reference = rest[1..]
+            .find(';')
+            .map(|end| &rest[1..end + 1])
+            .and_then(|name| Some((name, decode_reference(name)?)));
+        match reference {
+            Some((name, c)) => {
+                decoded.push(c);
+                rest = &rest[name.len() + 2..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+fn decode_reference(name: &str) -> Option<char> {
+    match name {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => {
+            let number = name.strip_prefix('#')?;
+            let code = match number.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => number.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use super::prelude::*;
+use wasm_bindgen::JsValue;
+
+/// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#event-onChanged>
+pub fn on_changed() -> OnChanged {
+    OnChanged(bookmarks_ffi().on_changed())
+}
+
+define_event! {
+    /// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#event-onChanged>
+    pub struct OnChanged, OnChangedEventListener;
+    fn(id: String, info: JsValue) -> (id: BookmarkId, info: ChangeInfo) {
+        Some((BookmarkId::from(id), serde_from_js(info).ok()?))
+    }
+}
+
+/// The new title and URL.
+///
+/// Currently, only changes of these two properties trigger an `onChanged` event.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeInfo {
+    pub title: String,
+    pub url: Option<String>,
+}
@@ -0,0 +1,25 @@
+use super::prelude::*;
+use crate::util::optional_event;
+use wasm_bindgen::JsValue;
+
+/// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#event-onChildrenReordered>
+///
+/// Fails in Firefox, which does not support this event.
+pub fn on_children_reordered() -> Result<OnChildrenReordered, Error> {
+    optional_event(&bookmarks(), "bookmarks", "onChildrenReordered").map(OnChildrenReordered)
+}
+
+define_event! {
+    /// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#event-onChildrenReordered>
+    pub struct OnChildrenReordered, OnChildrenReorderedEventListener;
+    fn(id: String, info: JsValue) -> (id: BookmarkId, info: ReorderInfo) {
+        Some((BookmarkId::from(id), serde_from_js(info).ok()?))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReorderInfo {
+    /// The IDs of the children in their new order.
+    pub child_ids: Vec<BookmarkId>,
+}
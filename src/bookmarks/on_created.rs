@@ -0,0 +1,15 @@
+use super::{prelude::*, BookmarkTreeNode};
+use wasm_bindgen::JsValue;
+
+/// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#event-onCreated>
+pub fn on_created() -> OnCreated {
+    OnCreated(bookmarks_ffi().on_created())
+}
+
+define_event! {
+    /// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#event-onCreated>
+    pub struct OnCreated, OnCreatedEventListener;
+    fn(id: String, node: JsValue) -> (id: BookmarkId, node: BookmarkTreeNode) {
+        Some((BookmarkId::from(id), serde_from_js(node).ok()?))
+    }
+}
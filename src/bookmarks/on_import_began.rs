@@ -0,0 +1,17 @@
+use super::prelude::*;
+use crate::util::optional_event;
+
+/// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#event-onImportBegan>
+///
+/// Fails in Firefox, which does not support this event.
+pub fn on_import_began() -> Result<OnImportBegan, Error> {
+    optional_event(&bookmarks(), "bookmarks", "onImportBegan").map(OnImportBegan)
+}
+
+define_event! {
+    /// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#event-onImportBegan>
+    pub struct OnImportBegan, OnImportBeganEventListener;
+    fn() -> () {
+        Some(())
+    }
+}
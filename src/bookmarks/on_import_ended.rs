@@ -0,0 +1,17 @@
+use super::prelude::*;
+use crate::util::optional_event;
+
+/// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#event-onImportEnded>
+///
+/// Fails in Firefox, which does not support this event.
+pub fn on_import_ended() -> Result<OnImportEnded, Error> {
+    optional_event(&bookmarks(), "bookmarks", "onImportEnded").map(OnImportEnded)
+}
+
+define_event! {
+    /// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#event-onImportEnded>
+    pub struct OnImportEnded, OnImportEndedEventListener;
+    fn() -> () {
+        Some(())
+    }
+}
@@ -0,0 +1,24 @@
+use super::prelude::*;
+use wasm_bindgen::JsValue;
+
+/// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#event-onMoved>
+pub fn on_moved() -> OnMoved {
+    OnMoved(bookmarks_ffi().on_moved())
+}
+
+define_event! {
+    /// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#event-onMoved>
+    pub struct OnMoved, OnMovedEventListener;
+    fn(id: String, info: JsValue) -> (id: BookmarkId, info: MoveInfo) {
+        Some((BookmarkId::from(id), serde_from_js(info).ok()?))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveInfo {
+    pub parent_id: BookmarkId,
+    pub index: u32,
+    pub old_parent_id: BookmarkId,
+    pub old_index: u32,
+}
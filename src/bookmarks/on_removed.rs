@@ -0,0 +1,25 @@
+use super::{prelude::*, BookmarkTreeNode};
+use wasm_bindgen::JsValue;
+
+/// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#event-onRemoved>
+pub fn on_removed() -> OnRemoved {
+    OnRemoved(bookmarks_ffi().on_removed())
+}
+
+define_event! {
+    /// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#event-onRemoved>
+    pub struct OnRemoved, OnRemovedEventListener;
+    fn(id: String, info: JsValue) -> (id: BookmarkId, info: RemoveInfo) {
+        Some((BookmarkId::from(id), serde_from_js(info).ok()?))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoveInfo {
+    pub parent_id: BookmarkId,
+    pub index: u32,
+
+    /// The removed node including its children.
+    pub node: BookmarkTreeNode,
+}
@@ -0,0 +1,25 @@
+use super::prelude::*;
+
+/// <https://developer.chrome.com/docs/extensions/reference/bookmarks/#type-search-query>
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Query<'a> {
+    /// A string of words and quoted phrases that are matched against bookmark URLs and titles.
+    pub query: Option<&'a str>,
+
+    /// The title of the bookmark; matches verbatim.
+    pub title: Option<&'a str>,
+
+    /// The URL of the bookmark; matches verbatim. Note that folders have no URL.
+    pub url: Option<&'a str>,
+}
+
+impl<'a> From<&'a str> for Query<'a> {
+    fn from(q: &'a str) -> Self {
+        Self {
+            query: Some(q),
+            title: None,
+            url: None,
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use super::{BookmarkId, BookmarkTreeNode};
+use std::collections::{BTreeMap, VecDeque};
+
+impl BookmarkTreeNode {
+    /// Returns `true` if this node is a folder, i.e. it has no URL.
+    pub fn is_folder(&self) -> bool {
+        self.url.is_none()
+    }
+
+    /// Returns the children of this node or an empty slice
+    /// if the children have not been fetched or this node is a bookmark.
+    pub fn child_nodes(&self) -> &[Self] {
+        self.children.as_deref().unwrap_or_default()
+    }
+
+    /// Iterates over this node and all its descendants in depth-first pre-order.
+    pub fn iter_depth_first(&self) -> DepthFirst<'_> {
+        DepthFirst { stack: vec![self] }
+    }
+
+    /// Iterates over this node and all its descendants level by level.
+    pub fn iter_breadth_first(&self) -> BreadthFirst<'_> {
+        BreadthFirst {
+            queue: VecDeque::from([self]),
+        }
+    }
+
+    /// Finds this node or a descendant by its ID.
+    pub fn find_by_id(&self, id: &BookmarkId) -> Option<&Self> {
+        self.iter_depth_first().find(|node| &node.id == id)
+    }
+
+    /// Finds all bookmarks with exactly the given URL.
+    pub fn find_by_url<'a>(&'a self, url: &'a str) -> impl Iterator<Item = &'a Self> + 'a {
+        self.iter_depth_first()
+            .filter(move |node| node.url.as_deref() == Some(url))
+    }
+
+    /// Returns the nodes from this node down to the node with the given ID (both inclusive).
+    pub fn path(&self, id: &BookmarkId) -> Option<Vec<&Self>> {
+        if &self.id == id {
+            return Some(vec![self]);
+        }
+        self.child_nodes().iter().find_map(|child| {
+            let mut path = child.path(id)?;
+            path.insert(0, self);
+            Some(path)
+        })
+    }
+
+    /// Returns the titles of the folders that contain the node with the given ID,
+    /// joined by `/`, e.g. `"Bookmarks Bar/Work/Infra"`.
+    ///
+    /// The untitled root node of the whole bookmark tree is omitted.
+    pub fn folder_path(&self, id: &BookmarkId) -> Option<String> {
+        let mut path = self.path(id)?;
+        path.pop();
+        let titles: Vec<_> = path
+            .into_iter()
+            .filter(|folder| folder.parent_id.is_some())
+            .map(|folder| folder.title.as_str())
+            .collect();
+        Some(titles.join("/"))
+    }
+
+    /// Counts this node and all its descendants.
+    pub fn count(&self) -> NodeCount {
+        self.iter_depth_first()
+            .fold(NodeCount::default(), |mut count, node| {
+                if node.is_folder() {
+                    count.folders += 1;
+                } else {
+                    count.bookmarks += 1;
+                }
+                count
+            })
+    }
+
+    /// Returns all URLs that are bookmarked more than once with the corresponding bookmarks.
+    pub fn duplicate_urls(&self) -> BTreeMap<&str, Vec<&Self>> {
+        let mut by_url = BTreeMap::<_, Vec<_>>::new();
+        for node in self.iter_depth_first() {
+            if let Some(url) = &node.url {
+                by_url.entry(url.as_str()).or_default().push(node);
+            }
+        }
+        by_url.retain(|_, nodes| nodes.len() > 1);
+        by_url
+    }
+}
+
+/// The number of bookmarks and folders in a tree.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NodeCount {
+    pub bookmarks: usize,
+    pub folders: usize,
+}
+
+/// Iterator returned by [`BookmarkTreeNode::iter_depth_first`].
+pub struct DepthFirst<'a> {
+    stack: Vec<&'a BookmarkTreeNode>,
+}
+
+impl<'a> Iterator for DepthFirst<'a> {
+    type Item = &'a BookmarkTreeNode;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.stack.extend(node.child_nodes().iter().rev());
+        Some(node)
+    }
+}
+
+/// Iterator returned by [`BookmarkTreeNode::iter_breadth_first`].
+pub struct BreadthFirst<'a> {
+    queue: VecDeque<&'a BookmarkTreeNode>,
+}
+
+impl<'a> Iterator for BreadthFirst<'a> {
+    type Item = &'a BookmarkTreeNode;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.queue.pop_front()?;
+        self.queue.extend(node.child_nodes());
+        Some(node)
+    }
+}
@@ -0,0 +1,165 @@
+use crate::Error;
+use js_sys::Reflect;
+use wasm_bindgen::{prelude::*, JsCast};
+use web_extensions_sys as sys;
+
+#[wasm_bindgen]
+extern "C" {
+    // `web_extensions_sys::Runtime` does not expose these methods (yet).
+    type InfoRuntime;
+
+    #[wasm_bindgen(method, js_name = getManifest)]
+    fn get_manifest(this: &InfoRuntime) -> JsValue;
+
+    // Only available in Firefox.
+    #[wasm_bindgen(catch, method, js_name = getBrowserInfo)]
+    async fn get_browser_info(this: &InfoRuntime) -> Result<JsValue, JsValue>;
+}
+
+/// The global object that provides the extension APIs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Namespace {
+    /// The promise-based `browser` namespace, e.g. of Firefox and Safari.
+    Browser,
+    /// The `chrome` namespace of Chromium-based browsers.
+    Chrome,
+}
+
+impl Namespace {
+    /// The name of the global object.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Browser => "browser",
+            Self::Chrome => "chrome",
+        }
+    }
+}
+
+/// The entry point to the extension APIs of the current browser.
+#[derive(Debug, Clone)]
+pub struct Browser {
+    // `sys::Browser` is neither `Clone` nor `Debug`.
+    api: js_sys::Object,
+    namespace: Namespace,
+}
+
+impl Browser {
+    /// Looks up the extension APIs in the global scope.
+    ///
+    /// The `browser` namespace is preferred, because it is promise-based
+    /// in every browser that provides it, otherwise `chrome` is used.
+    /// Returns `None` if neither provides the `runtime` API,
+    /// e.g. outside of an extension.
+    pub fn detect() -> Option<Self> {
+        let global = js_sys::global();
+        [Namespace::Browser, Namespace::Chrome]
+            .into_iter()
+            .find_map(|namespace| {
+                let api = Reflect::get(&global, &JsValue::from_str(namespace.name())).ok()?;
+                // Web pages may see a `chrome` object without extension APIs.
+                let runtime = Reflect::get(&api, &JsValue::from_str("runtime")).ok()?;
+                (api.is_object() && runtime.is_object()).then(|| Self {
+                    api: api.unchecked_into(),
+                    namespace,
+                })
+            })
+    }
+
+    pub fn namespace(&self) -> Namespace {
+        self.namespace
+    }
+
+    /// The low level bindings of the detected namespace.
+    pub fn api(&self) -> &sys::Browser {
+        self.api.unchecked_ref()
+    }
+}
+
+thread_local! {
+    static BROWSER: Option<Browser> = Browser::detect();
+}
+
+/// Returns the [detected](Browser::detect) browser.
+///
+/// # Panics
+///
+/// Panics if no extension APIs are available.
+pub fn browser() -> Browser {
+    BROWSER.with(|browser| {
+        browser
+            .clone()
+            .expect("neither `browser` nor `chrome` provides extension APIs")
+    })
+}
+
+/// Information about the browser the extension is running in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrowserInfo {
+    /// The name of the browser, e.g. `"Firefox"`, `"Chrome"` or `"Edge"`.
+    pub name: String,
+
+    /// The version of the browser, e.g. `"107.0.5304.87"`.
+    pub version: String,
+
+    /// The `manifest_version` of the extension.
+    pub manifest_version: u32,
+
+    pub namespace: Namespace,
+}
+
+/// Returns information about the browser.
+///
+/// Firefox provides the name and version with `runtime.getBrowserInfo()`,
+/// other browsers are identified by their user agent.
+pub async fn browser_info() -> Result<BrowserInfo, Error> {
+    let browser = browser();
+    let runtime: InfoRuntime = browser.api().runtime().unchecked_into();
+    let manifest = runtime.get_manifest();
+    let manifest_version = Reflect::get(&manifest, &JsValue::from_str("manifest_version"))?
+        .as_f64()
+        .unwrap_or_default() as u32;
+    let (name, version) = if Reflect::has(&runtime, &JsValue::from_str("getBrowserInfo"))? {
+        let info = runtime.get_browser_info().await?;
+        let property = |name| {
+            Reflect::get(&info, &JsValue::from_str(name))
+                .ok()
+                .and_then(|value| value.as_string())
+                .unwrap_or_default()
+        };
+        (property("name"), property("version"))
+    } else {
+        let user_agent = Reflect::get(&js_sys::global(), &JsValue::from_str("navigator"))
+            .and_then(|navigator| Reflect::get(&navigator, &JsValue::from_str("userAgent")))?
+            .as_string()
+            .unwrap_or_default();
+        let (name, version) = browser_from_user_agent(&user_agent).unwrap_or_default();
+        (name.to_owned(), version.to_owned())
+    };
+    Ok(BrowserInfo {
+        name,
+        version,
+        manifest_version,
+        namespace: browser.namespace(),
+    })
+}
+
+/// Extracts the name and version of the browser from a user agent string.
+pub fn browser_from_user_agent(user_agent: &str) -> Option<(&'static str, &str)> {
+    // Chromium-based browsers also mention Chrome, so they are checked first.
+    const PRODUCTS: &[(&str, &str)] = &[
+        ("Edg/", "Edge"),
+        ("OPR/", "Opera"),
+        ("Firefox/", "Firefox"),
+        ("Chromium/", "Chromium"),
+        ("Chrome/", "Chrome"),
+        ("Version/", "Safari"),
+    ];
+    PRODUCTS.iter().find_map(|(token, name)| {
+        let start = user_agent.find(token)? + token.len();
+        let version = user_agent[start..]
+            .split(|c: char| c.is_whitespace() || c == ';' || c == ')')
+            .next()
+            .unwrap_or_default();
+        Some((*name, version))
+    })
+}
@@ -6,10 +6,10 @@ use crate::{
 };
 
 use serde::{Deserialize, Serialize};
-use web_extensions_sys::{browser, ContextualIdentities};
+use web_extensions_sys::ContextualIdentities;
 
 fn contextual_identities() -> ContextualIdentities {
-    browser.contextual_identities()
+    crate::browser().api().contextual_identities()
 }
 
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -0,0 +1,75 @@
+use super::{prelude::*, DangerType, DownloadItem, InterruptReason, State};
+use std::path::PathBuf;
+
+/// The change of a single property of a [`DownloadItem`](super::DownloadItem).
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Delta<T> {
+    /// The value after the change.
+    pub current: Option<T>,
+
+    /// The value before the change.
+    pub previous: Option<T>,
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#type-StringDelta>
+pub type StringDelta = Delta<String>;
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#type-BooleanDelta>
+pub type BoolDelta = Delta<bool>;
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#type-DoubleDelta>
+pub type NumberDelta = Delta<i64>;
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#event-onChanged-callback-downloadDelta>
+///
+/// Only the properties that changed are present.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadDelta {
+    /// The ID of the download that changed.
+    pub id: DownloadId,
+    pub can_resume: Option<BoolDelta>,
+    pub danger: Option<Delta<DangerType>>,
+    pub end_time: Option<Delta<Timestamp>>,
+    pub error: Option<Delta<InterruptReason>>,
+    pub exists: Option<BoolDelta>,
+    pub file_size: Option<NumberDelta>,
+    pub filename: Option<Delta<PathBuf>>,
+    pub final_url: Option<StringDelta>,
+    pub mime: Option<StringDelta>,
+    pub paused: Option<BoolDelta>,
+    pub start_time: Option<Delta<Timestamp>>,
+    pub state: Option<Delta<State>>,
+    pub total_bytes: Option<NumberDelta>,
+    pub url: Option<StringDelta>,
+}
+
+impl DownloadDelta {
+    /// Updates the changed properties of `item`.
+    pub fn apply(&self, item: &mut DownloadItem) {
+        fn update<T: Clone>(value: &mut T, delta: &Option<Delta<T>>) {
+            if let Some(current) = delta.as_ref().and_then(|delta| delta.current.as_ref()) {
+                *value = current.clone();
+            }
+        }
+        fn update_optional<T: Clone>(value: &mut Option<T>, delta: &Option<Delta<T>>) {
+            if let Some(delta) = delta {
+                value.clone_from(&delta.current);
+            }
+        }
+        update(&mut item.can_resume, &self.can_resume);
+        update(&mut item.danger, &self.danger);
+        update_optional(&mut item.end_time, &self.end_time);
+        update_optional(&mut item.error, &self.error);
+        update(&mut item.exists, &self.exists);
+        update(&mut item.file_size, &self.file_size);
+        update(&mut item.filename, &self.filename);
+        update_optional(&mut item.final_url, &self.final_url);
+        update(&mut item.mime, &self.mime);
+        update(&mut item.paused, &self.paused);
+        update(&mut item.start_time, &self.start_time);
+        update(&mut item.state, &self.state);
+        update(&mut item.total_bytes, &self.total_bytes);
+        update(&mut item.url, &self.url);
+    }
+}
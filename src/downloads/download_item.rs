@@ -0,0 +1,164 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use super::DownloadId;
+use crate::Timestamp;
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#type-DownloadItem>
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadItem {
+    /// The identifier for the extension that initiated this download
+    /// if this download was initiated by an extension.
+    pub by_extension_id: Option<String>,
+
+    /// The localized name of the extension that initiated this download
+    /// if this download was initiated by an extension.
+    pub by_extension_name: Option<String>,
+
+    /// Number of bytes received so far from the host,
+    /// without considering file compression.
+    pub bytes_received: u64,
+
+    /// Whether the download is in progress and paused,
+    /// or else interrupted and can resume starting from where it was interrupted.
+    pub can_resume: bool,
+
+    /// Indication of whether this download is thought to be safe or known to be suspicious.
+    pub danger: DangerType,
+
+    /// The time when the download ended.
+    pub end_time: Option<Timestamp>,
+
+    /// Why the download was interrupted.
+    pub error: Option<InterruptReason>,
+
+    /// Estimated time when the download will complete.
+    pub estimated_end_time: Option<Timestamp>,
+
+    /// Whether the downloaded file still exists.
+    pub exists: bool,
+
+    /// Number of bytes in the whole file post-decompression, or `-1` if unknown.
+    pub file_size: i64,
+
+    /// Absolute local path.
+    pub filename: PathBuf,
+
+    /// The absolute URL that this download is being made from, after all redirects.
+    pub final_url: Option<String>,
+
+    /// An identifier that is persistent across browser sessions.
+    pub id: DownloadId,
+
+    /// False if this download is recorded in the history, true if it is not recorded.
+    pub incognito: bool,
+
+    /// The file's MIME type.
+    pub mime: String,
+
+    /// True if the download has stopped reading data from the host,
+    /// but kept the connection open.
+    pub paused: bool,
+
+    /// Absolute URL.
+    pub referrer: Option<String>,
+
+    /// The time when the download began.
+    pub start_time: Timestamp,
+
+    /// Indicates whether the download is progressing, interrupted, or complete.
+    pub state: State,
+
+    /// Number of bytes in the whole file, without considering file compression,
+    /// or `-1` if unknown.
+    pub total_bytes: i64,
+
+    /// The absolute URL that this download initiated from, before any redirects.
+    pub url: String,
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#type-State>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum State {
+    /// The download is currently receiving data from the server.
+    #[serde(rename(serialize = "in_progress", deserialize = "in_progress"))]
+    InProgress,
+    /// An error broke the connection with the file host.
+    #[serde(rename(serialize = "interrupted", deserialize = "interrupted"))]
+    Interrupted,
+    /// The download completed successfully.
+    #[serde(rename(serialize = "complete", deserialize = "complete"))]
+    Complete,
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#type-DangerType>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DangerType {
+    /// The download's filename is suspicious.
+    File,
+    /// The download's URL is known to be malicious.
+    Url,
+    /// The downloaded file is known to be malicious.
+    Content,
+    /// The download's URL is not commonly downloaded and could be dangerous.
+    Uncommon,
+    /// The download came from a host known to distribute malicious binaries
+    /// and is likely dangerous.
+    Host,
+    /// The download is potentially unwanted or unsafe.
+    Unwanted,
+    /// The download presents no known danger to the user's computer.
+    Safe,
+    /// The user has accepted the dangerous download.
+    Accepted,
+    /// Enterprise-related values, and any other value
+    /// that is not known to this crate (yet).
+    ///
+    /// Cannot be used in a [`DownloadQuery`](super::DownloadQuery),
+    /// because the actual value is unknown.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#type-InterruptReason>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum InterruptReason {
+    FileFailed,
+    FileAccessDenied,
+    FileNoSpace,
+    FileNameTooLong,
+    FileTooLarge,
+    FileVirusInfected,
+    FileTransientError,
+    FileBlocked,
+    FileSecurityCheckFailed,
+    FileTooShort,
+    FileHashMismatch,
+    FileSameAsSource,
+    NetworkFailed,
+    NetworkTimeout,
+    NetworkDisconnected,
+    NetworkServerDown,
+    NetworkInvalidRequest,
+    ServerFailed,
+    ServerNoRange,
+    ServerBadContent,
+    ServerUnauthorized,
+    ServerCertProblem,
+    ServerForbidden,
+    ServerUnreachable,
+    ServerContentLengthMismatch,
+    ServerCrossOriginRedirect,
+    UserCanceled,
+    UserShutdown,
+    Crash,
+    /// Any value that is not known to this crate (yet).
+    ///
+    /// Cannot be used in a [`DownloadQuery`](super::DownloadQuery),
+    /// because the actual value is unknown.
+    #[serde(other, skip_serializing)]
+    Other,
+}
@@ -0,0 +1,73 @@
+use serde::Serialize;
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#type-DownloadOptions>
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadOptions<'a> {
+    /// The URL to download.
+    pub url: &'a str,
+
+    /// Post body.
+    pub body: Option<&'a str>,
+
+    /// The action to take if `filename` already exists.
+    pub conflict_action: Option<FilenameConflictAction>,
+
+    /// A file path relative to the Downloads directory to contain the downloaded file,
+    /// possibly containing subdirectories.
+    ///
+    /// Absolute paths, empty paths, and paths containing back-references `..` will cause an error.
+    pub filename: Option<&'a str>,
+
+    /// Extra HTTP headers to send with the request if the URL uses the HTTP[s] protocol.
+    pub headers: Option<Vec<HeaderNameValuePair<'a>>>,
+
+    /// The HTTP method to use if the URL uses the HTTP[S] protocol.
+    pub method: Option<HttpMethod>,
+
+    /// Use a file-chooser to allow the user to select a filename
+    /// regardless of whether `filename` is set or already exists.
+    pub save_as: Option<bool>,
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#type-FilenameConflictAction>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum FilenameConflictAction {
+    /// To avoid duplication, the filename is changed to include a counter before the filename extension.
+    #[serde(rename(serialize = "uniquify"))]
+    Uniquify,
+    /// The existing file will be overwritten with the new file.
+    #[serde(rename(serialize = "overwrite"))]
+    Overwrite,
+    /// The user will be prompted with a file chooser dialog.
+    #[serde(rename(serialize = "prompt"))]
+    Prompt,
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#type-HttpMethod>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum HttpMethod {
+    #[serde(rename(serialize = "GET"))]
+    Get,
+    #[serde(rename(serialize = "POST"))]
+    Post,
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#type-HeaderNameValuePair>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct HeaderNameValuePair<'a> {
+    /// Name of the HTTP header.
+    pub name: &'a str,
+
+    /// Value of the HTTP header.
+    pub value: &'a str,
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#type-GetFileIconOptions>
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct GetFileIconOptions {
+    /// The size of the returned icon.
+    ///
+    /// Supported sizes are 16 and 32, defaults to 32.
+    pub size: Option<u32>,
+}
@@ -0,0 +1,163 @@
+use serde::Serialize;
+
+use super::{DangerType, DownloadId, InterruptReason, State};
+use crate::{timestamp::serialize_iso8601, Timestamp};
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#type-DownloadQuery>
+///
+/// All conditions are combined, so only downloads matching every given condition are returned.
+///
+/// The query can be built in a chain of owned builder methods,
+/// e.g. `DownloadQuery::default().state(State::Complete).limit(10)`,
+/// or modified in place with the corresponding `set_*` methods.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadQuery<'a> {
+    /// Number of bytes received so far from the host, without considering file compression.
+    pub bytes_received: Option<u64>,
+
+    /// Indication of whether this download is thought to be safe or known to be suspicious.
+    pub danger: Option<DangerType>,
+
+    /// The time when the download ended.
+    #[serde(serialize_with = "serialize_iso8601")]
+    pub end_time: Option<Timestamp>,
+
+    /// Limits results to downloads that ended after the given time.
+    #[serde(serialize_with = "serialize_iso8601")]
+    pub ended_after: Option<Timestamp>,
+
+    /// Limits results to downloads that ended before the given time.
+    #[serde(serialize_with = "serialize_iso8601")]
+    pub ended_before: Option<Timestamp>,
+
+    /// Why a download was interrupted.
+    pub error: Option<InterruptReason>,
+
+    /// Whether the downloaded file exists.
+    pub exists: Option<bool>,
+
+    /// Number of bytes in the whole file post-decompression, or `-1` if unknown.
+    pub file_size: Option<i64>,
+
+    /// Absolute local path.
+    pub filename: Option<&'a str>,
+
+    /// Limits results to downloads whose `filename` matches the given regular expression.
+    pub filename_regex: Option<&'a str>,
+
+    /// The absolute URL that this download is being made from, after all redirects.
+    pub final_url: Option<&'a str>,
+
+    /// Limits results to downloads whose `final_url` matches the given regular expression.
+    pub final_url_regex: Option<&'a str>,
+
+    /// The ID of the download to query.
+    pub id: Option<DownloadId>,
+
+    /// The maximum number of matching downloads returned.
+    ///
+    /// Defaults to 1000. Set to 0 in order to return all matching downloads.
+    pub limit: Option<u32>,
+
+    /// The file's MIME type.
+    pub mime: Option<&'a str>,
+
+    /// Sorts the results by these properties of [`DownloadItem`](super::DownloadItem),
+    /// e.g. `["-startTime"]` sorts by start time in descending order.
+    pub order_by: Option<Vec<&'a str>>,
+
+    /// True if the download has stopped reading data from the host, but kept the connection open.
+    pub paused: Option<bool>,
+
+    /// Search terms that must all be contained in the `filename` or `url`.
+    ///
+    /// Terms beginning with a dash `-` must not be contained.
+    pub query: Option<Vec<&'a str>>,
+
+    /// The time when the download began.
+    #[serde(serialize_with = "serialize_iso8601")]
+    pub start_time: Option<Timestamp>,
+
+    /// Limits results to downloads that started after the given time.
+    #[serde(serialize_with = "serialize_iso8601")]
+    pub started_after: Option<Timestamp>,
+
+    /// Limits results to downloads that started before the given time.
+    #[serde(serialize_with = "serialize_iso8601")]
+    pub started_before: Option<Timestamp>,
+
+    /// Indicates whether the download is progressing, interrupted, or complete.
+    pub state: Option<State>,
+
+    /// Number of bytes in the whole file, without considering file compression, or `-1` if unknown.
+    pub total_bytes: Option<i64>,
+
+    /// Limits results to downloads whose `total_bytes` is greater than the given integer.
+    pub total_bytes_greater: Option<i64>,
+
+    /// Limits results to downloads whose `total_bytes` is less than the given integer.
+    pub total_bytes_less: Option<i64>,
+
+    /// The absolute URL that this download initiated from, before any redirects.
+    pub url: Option<&'a str>,
+
+    /// Limits results to downloads whose `url` matches the given regular expression.
+    pub url_regex: Option<&'a str>,
+}
+
+macro_rules! builder_methods {
+    ($($field:ident, $set_field:ident: $ty:ty;)*) => {
+        impl<'a> DownloadQuery<'a> {
+            $(
+                #[doc = concat!("Sets [`", stringify!($field), "`](Self::", stringify!($field), ").")]
+                pub fn $field(mut self, $field: $ty) -> Self {
+                    self.$field = Some($field);
+                    self
+                }
+
+                #[doc = concat!("Sets [`", stringify!($field), "`](Self::", stringify!($field), ") in place.")]
+                pub fn $set_field(&mut self, $field: $ty) -> &mut Self {
+                    self.$field = Some($field);
+                    self
+                }
+            )*
+        }
+    };
+}
+
+builder_methods! {
+    bytes_received, set_bytes_received: u64;
+    danger, set_danger: DangerType;
+    end_time, set_end_time: Timestamp;
+    ended_after, set_ended_after: Timestamp;
+    ended_before, set_ended_before: Timestamp;
+    error, set_error: InterruptReason;
+    exists, set_exists: bool;
+    file_size, set_file_size: i64;
+    filename, set_filename: &'a str;
+    filename_regex, set_filename_regex: &'a str;
+    final_url, set_final_url: &'a str;
+    final_url_regex, set_final_url_regex: &'a str;
+    id, set_id: DownloadId;
+    limit, set_limit: u32;
+    mime, set_mime: &'a str;
+    order_by, set_order_by: Vec<&'a str>;
+    paused, set_paused: bool;
+    query, set_query: Vec<&'a str>;
+    start_time, set_start_time: Timestamp;
+    started_after, set_started_after: Timestamp;
+    started_before, set_started_before: Timestamp;
+    state, set_state: State;
+    total_bytes, set_total_bytes: i64;
+    total_bytes_greater, set_total_bytes_greater: i64;
+    total_bytes_less, set_total_bytes_less: i64;
+    url, set_url: &'a str;
+    url_regex, set_url_regex: &'a str;
+}
+
+impl<'a> From<&'a str> for DownloadQuery<'a> {
+    fn from(q: &'a str) -> Self {
+        Self::default().query(vec![q])
+    }
+}
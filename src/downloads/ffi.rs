@@ -0,0 +1,62 @@
+//! Bindings of the methods and events that `web_extensions_sys::Downloads` does not cover (yet).
+//!
+//! Some of these methods do not return a promise in every browser,
+//! so they are bound synchronously and their results are resolved afterwards.
+
+use js_sys::{Object, Promise};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use web_extensions_sys as sys;
+
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type Downloads;
+
+    #[wasm_bindgen(catch, method, js_name = acceptDanger)]
+    pub(crate) fn accept_danger(this: &Downloads, download_id: i32) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, method)]
+    pub(crate) fn cancel(this: &Downloads, download_id: i32) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, method)]
+    pub(crate) fn erase(this: &Downloads, query: &Object) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, method, js_name = getFileIcon)]
+    pub(crate) fn get_file_icon(
+        this: &Downloads,
+        download_id: i32,
+        options: &Object,
+    ) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, method)]
+    pub(crate) fn open(this: &Downloads, download_id: i32) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, method)]
+    pub(crate) fn pause(this: &Downloads, download_id: i32) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, method, js_name = removeFile)]
+    pub(crate) fn remove_file(this: &Downloads, download_id: i32) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, method)]
+    pub(crate) fn resume(this: &Downloads, download_id: i32) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, method)]
+    pub(crate) fn show(this: &Downloads, download_id: i32) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, method, js_name = showDefaultFolder)]
+    pub(crate) fn show_default_folder(this: &Downloads) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(method, getter, js_name = onChanged)]
+    pub(crate) fn on_changed(this: &Downloads) -> sys::EventTarget;
+
+    #[wasm_bindgen(method, getter, js_name = onCreated)]
+    pub(crate) fn on_created(this: &Downloads) -> sys::EventTarget;
+
+    #[wasm_bindgen(method, getter, js_name = onErased)]
+    pub(crate) fn on_erased(this: &Downloads) -> sys::EventTarget;
+}
+
+/// Waits for the result if it is a promise, otherwise returns it as it is.
+pub(crate) async fn resolve(result: Result<JsValue, JsValue>) -> Result<JsValue, JsValue> {
+    JsFuture::from(Promise::resolve(&result?)).await
+}
@@ -0,0 +1,138 @@
+//! Wrapper for the [`chrome.downloads` API](https://developer.chrome.com/docs/extensions/reference/downloads/).
+
+pub(crate) mod prelude {
+    pub(crate) use crate::event_stream::define_event;
+    pub(crate) use crate::util::{
+        js_from_serde, object_from_js, serde_from_js, serde_from_js_result,
+    };
+    pub use crate::{downloads::DownloadId, event_listener::EventListener, Error, Timestamp};
+    pub use serde::{Deserialize, Serialize};
+    pub use wasm_bindgen::closure::Closure;
+    pub use web_extensions_sys as sys;
+
+    pub fn downloads() -> sys::Downloads {
+        crate::browser().api().downloads()
+    }
+
+    pub(crate) fn downloads_ffi() -> super::ffi::Downloads {
+        use wasm_bindgen::JsCast;
+        downloads().unchecked_into()
+    }
+}
+
+use self::prelude::*;
+
+mod download_delta;
+mod download_item;
+mod download_options;
+mod download_query;
+mod ffi;
+mod on_changed;
+mod on_created;
+mod on_determining_filename;
+mod on_erased;
+mod tracker;
+
+pub use self::{
+    download_delta::*, download_item::*, download_options::*, download_query::*, on_changed::*,
+    on_created::*, on_determining_filename::*, on_erased::*, tracker::*,
+};
+
+/// The ID of a download.
+///
+/// Download IDs are persistent across browser sessions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DownloadId(pub(crate) i32);
+
+impl From<i32> for DownloadId {
+    fn from(id: i32) -> Self {
+        Self(id)
+    }
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#method-search>
+pub async fn search(query: &DownloadQuery<'_>) -> Result<Vec<DownloadItem>, Error> {
+    let js_query = js_from_serde(query)?;
+    let js_value = downloads().search(&js_query).await?;
+    serde_from_js(js_value)
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#method-download>
+pub async fn download(options: &DownloadOptions<'_>) -> Result<DownloadId, Error> {
+    let js_options = js_from_serde(options)?;
+    let result = downloads().download(object_from_js(&js_options)?).await;
+    serde_from_js_result(result)
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#method-pause>
+pub async fn pause(download_id: DownloadId) -> Result<(), Error> {
+    ffi::resolve(downloads_ffi().pause(download_id.0)).await?;
+    Ok(())
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#method-resume>
+pub async fn resume(download_id: DownloadId) -> Result<(), Error> {
+    ffi::resolve(downloads_ffi().resume(download_id.0)).await?;
+    Ok(())
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#method-cancel>
+pub async fn cancel(download_id: DownloadId) -> Result<(), Error> {
+    ffi::resolve(downloads_ffi().cancel(download_id.0)).await?;
+    Ok(())
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#method-erase>
+///
+/// Erases matching downloads from the history without deleting the downloaded files
+/// and returns the IDs of the erased downloads.
+pub async fn erase(query: &DownloadQuery<'_>) -> Result<Vec<DownloadId>, Error> {
+    let js_query = js_from_serde(query)?;
+    let result = ffi::resolve(downloads_ffi().erase(object_from_js(&js_query)?)).await;
+    serde_from_js_result(result)
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#method-removeFile>
+pub async fn remove_file(download_id: DownloadId) -> Result<(), Error> {
+    ffi::resolve(downloads_ffi().remove_file(download_id.0)).await?;
+    Ok(())
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#method-open>
+pub async fn open(download_id: DownloadId) -> Result<(), Error> {
+    ffi::resolve(downloads_ffi().open(download_id.0)).await?;
+    Ok(())
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#method-show>
+pub async fn show(download_id: DownloadId) -> Result<(), Error> {
+    ffi::resolve(downloads_ffi().show(download_id.0)).await?;
+    Ok(())
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#method-showDefaultFolder>
+pub async fn show_default_folder() -> Result<(), Error> {
+    ffi::resolve(downloads_ffi().show_default_folder()).await?;
+    Ok(())
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#method-getFileIcon>
+///
+/// Returns the URL of an image that represents the download,
+/// or `None` if no icon is available.
+pub async fn get_file_icon(
+    download_id: DownloadId,
+    options: GetFileIconOptions,
+) -> Result<Option<String>, Error> {
+    let js_options = js_from_serde(&options)?;
+    let result =
+        ffi::resolve(downloads_ffi().get_file_icon(download_id.0, object_from_js(&js_options)?))
+            .await;
+    serde_from_js_result(result)
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#method-acceptDanger>
+pub async fn accept_danger(download_id: DownloadId) -> Result<(), Error> {
+    ffi::resolve(downloads_ffi().accept_danger(download_id.0)).await?;
+    Ok(())
+}
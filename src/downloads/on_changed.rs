@@ -0,0 +1,15 @@
+use super::{prelude::*, DownloadDelta};
+use wasm_bindgen::JsValue;
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#event-onChanged>
+pub fn on_changed() -> OnChanged {
+    OnChanged(downloads_ffi().on_changed())
+}
+
+define_event! {
+    /// <https://developer.chrome.com/docs/extensions/reference/downloads/#event-onChanged>
+    pub struct OnChanged, OnChangedEventListener;
+    fn(delta: JsValue) -> (delta: DownloadDelta) {
+        serde_from_js(delta).ok()
+    }
+}
@@ -0,0 +1,15 @@
+use super::{prelude::*, DownloadItem};
+use wasm_bindgen::JsValue;
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#event-onCreated>
+pub fn on_created() -> OnCreated {
+    OnCreated(downloads_ffi().on_created())
+}
+
+define_event! {
+    /// <https://developer.chrome.com/docs/extensions/reference/downloads/#event-onCreated>
+    pub struct OnCreated, OnCreatedEventListener;
+    fn(item: JsValue) -> (item: DownloadItem) {
+        serde_from_js(item).ok()
+    }
+}
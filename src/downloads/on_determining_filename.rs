@@ -0,0 +1,73 @@
+use super::{prelude::*, DownloadItem, FilenameConflictAction};
+use crate::util::{log_error, optional_event};
+use js_sys::Function;
+use std::future::Future;
+use wasm_bindgen::JsValue;
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#event-onDeterminingFilename>
+///
+/// Fails in Firefox, which does not support this event.
+pub fn on_determining_filename() -> Result<OnDeterminingFilename, Error> {
+    optional_event(&downloads(), "downloads", "onDeterminingFilename").map(OnDeterminingFilename)
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#event-onDeterminingFilename>
+pub struct OnDeterminingFilename(sys::EventTarget);
+
+pub struct OnDeterminingFilenameEventListener(EventListener<dyn FnMut(JsValue, Function) -> bool>);
+
+impl OnDeterminingFilenameEventListener {
+    pub fn forget(self) {
+        self.0.forget()
+    }
+}
+
+impl OnDeterminingFilename {
+    /// Adds a listener that may suggest a filename for new downloads.
+    ///
+    /// If the listener returns a future, the browser waits for it
+    /// and uses its suggestion, or the default filename if it resolves to `None`.
+    /// If the listener returns `None`, the download continues with the default filename,
+    /// as it does for downloads that cannot be deserialized.
+    /// A suggestion that cannot be serialized is logged to the console.
+    pub fn add_listener<F, L>(&self, mut listener: L) -> OnDeterminingFilenameEventListener
+    where
+        F: Future<Output = Option<FilenameSuggestion>> + 'static,
+        L: FnMut(DownloadItem) -> Option<F> + 'static,
+    {
+        let listener = Closure::new(move |item: JsValue, suggest: Function| {
+            let Ok(item) = serde_from_js(item) else {
+                return false;
+            };
+            let Some(suggestion) = listener(item) else {
+                return false;
+            };
+            wasm_bindgen_futures::spawn_local(async move {
+                let js_suggestion = match suggestion.await.map(|s| js_from_serde(&s)) {
+                    Some(Ok(js_suggestion)) => js_suggestion,
+                    Some(Err(err)) => {
+                        log_error("failed to serialize the filename suggestion", &err);
+                        // Suggesting nothing keeps the default filename.
+                        JsValue::UNDEFINED
+                    }
+                    None => JsValue::UNDEFINED,
+                };
+                let _ = suggest.call1(&JsValue::NULL, &js_suggestion);
+            });
+            // Tells the browser that `suggest` will be called asynchronously.
+            true
+        });
+        OnDeterminingFilenameEventListener(EventListener::raw_new(&self.0, listener))
+    }
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#type-FilenameSuggestion>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FilenameSuggestion {
+    /// The new target filename, relative to the Downloads directory.
+    pub filename: String,
+
+    /// The action to take if `filename` already exists.
+    pub conflict_action: Option<FilenameConflictAction>,
+}
@@ -0,0 +1,14 @@
+use super::prelude::*;
+
+/// <https://developer.chrome.com/docs/extensions/reference/downloads/#event-onErased>
+pub fn on_erased() -> OnErased {
+    OnErased(downloads_ffi().on_erased())
+}
+
+define_event! {
+    /// <https://developer.chrome.com/docs/extensions/reference/downloads/#event-onErased>
+    pub struct OnErased, OnErasedEventListener;
+    fn(download_id: i32) -> (download_id: DownloadId) {
+        Some(DownloadId::from(download_id))
+    }
+}
@@ -0,0 +1,361 @@
+use super::{
+    prelude::*, DownloadDelta, DownloadItem, DownloadQuery, OnChangedEventListener,
+    OnCreatedEventListener, OnErasedEventListener, State,
+};
+use crate::backend::{BrowserBackend, DownloadsBackend};
+use futures_channel::mpsc;
+use futures_core::Stream;
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    pin::Pin,
+    rc::Rc,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+/// The change of the [`State`] of a download.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StateTransition {
+    pub id: DownloadId,
+    pub from: State,
+    pub to: State,
+}
+
+/// The progress of a download.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Progress {
+    /// Number of bytes received so far.
+    pub bytes_received: u64,
+
+    /// Number of bytes of the whole file, if known.
+    pub total_bytes: Option<u64>,
+
+    /// The completed fraction between `0.0` and `1.0`, if the total size is known.
+    pub fraction: Option<f64>,
+
+    /// The transfer speed between the last two observations, if the download is in progress.
+    pub bytes_per_second: Option<f64>,
+
+    /// The estimated remaining time, if the total size and the speed are known.
+    pub eta: Option<Duration>,
+}
+
+#[derive(Debug, Clone)]
+struct TrackedDownload {
+    item: DownloadItem,
+    // Time in milliseconds since the epoch and number of received bytes
+    // of the last observation.
+    sample: (f64, u64),
+    bytes_per_second: Option<f64>,
+}
+
+/// The in-memory state of a [`DownloadTracker`].
+///
+/// This type does not access any browser API, the current time is passed
+/// as milliseconds since the epoch instead.
+#[derive(Debug, Default)]
+pub struct TrackedDownloads {
+    downloads: HashMap<DownloadId, TrackedDownload>,
+    subscribers: Vec<mpsc::UnboundedSender<StateTransition>>,
+}
+
+impl TrackedDownloads {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts or updates a download, e.g. a result of [`search`].
+    ///
+    /// Returns the state transition, if any.
+    pub fn insert(&mut self, item: DownloadItem, now: f64) -> Option<StateTransition> {
+        match self.downloads.get_mut(&item.id) {
+            Some(tracked) => {
+                let from = tracked.item.state;
+                tracked.item = item;
+                tracked.observe(now);
+                let transition = tracked_transition(from, tracked);
+                self.transition(transition)
+            }
+            None => {
+                let sample = (now, item.bytes_received);
+                self.downloads.insert(
+                    item.id,
+                    TrackedDownload {
+                        item,
+                        sample,
+                        bytes_per_second: None,
+                    },
+                );
+                None
+            }
+        }
+    }
+
+    /// Applies a change of a tracked download.
+    ///
+    /// Changes of unknown downloads are ignored.
+    /// Returns the state transition, if any.
+    pub fn apply(&mut self, delta: &DownloadDelta, now: f64) -> Option<StateTransition> {
+        let tracked = self.downloads.get_mut(&delta.id)?;
+        let (from, was_paused) = (tracked.item.state, tracked.item.paused);
+        delta.apply(&mut tracked.item);
+        // Deltas never carry the received bytes, so only a pause, a resumption
+        // or a state change makes the measured speed obsolete.
+        if tracked.item.state != from || tracked.item.paused != was_paused {
+            tracked.restart(now);
+        }
+        let transition = tracked_transition(from, tracked);
+        self.transition(transition)
+    }
+
+    /// Stops tracking a download.
+    pub fn remove(&mut self, id: DownloadId) -> Option<DownloadItem> {
+        self.downloads.remove(&id).map(|tracked| tracked.item)
+    }
+
+    pub fn get(&self, id: DownloadId) -> Option<&DownloadItem> {
+        self.downloads.get(&id).map(|tracked| &tracked.item)
+    }
+
+    pub fn items(&self) -> impl Iterator<Item = &DownloadItem> {
+        self.downloads.values().map(|tracked| &tracked.item)
+    }
+
+    pub fn progress(&self, id: DownloadId) -> Option<Progress> {
+        self.downloads.get(&id).map(TrackedDownload::progress)
+    }
+
+    /// Returns a stream of all subsequent state transitions.
+    pub fn transitions(&mut self) -> StateTransitions {
+        let (sender, receiver) = mpsc::unbounded();
+        self.subscribers.push(sender);
+        StateTransitions(receiver)
+    }
+
+    fn transition(&mut self, transition: Option<StateTransition>) -> Option<StateTransition> {
+        if let Some(transition) = transition {
+            self.subscribers
+                .retain(|subscriber| subscriber.unbounded_send(transition).is_ok());
+        }
+        transition
+    }
+}
+
+fn tracked_transition(from: State, tracked: &TrackedDownload) -> Option<StateTransition> {
+    let to = tracked.item.state;
+    (from != to).then_some(StateTransition {
+        id: tracked.item.id,
+        from,
+        to,
+    })
+}
+
+impl TrackedDownload {
+    fn observe(&mut self, now: f64) {
+        let (then, bytes_then) = self.sample;
+        let bytes_now = self.item.bytes_received;
+        if self.item.state != State::InProgress || self.item.paused {
+            self.bytes_per_second = None;
+        } else if now > then && bytes_now >= bytes_then {
+            let seconds = (now - then) / 1000.0;
+            self.bytes_per_second = Some((bytes_now - bytes_then) as f64 / seconds);
+        } else {
+            // Nothing new to learn from this observation.
+            return;
+        }
+        self.sample = (now, bytes_now);
+    }
+
+    fn restart(&mut self, now: f64) {
+        self.bytes_per_second = None;
+        self.sample = (now, self.item.bytes_received);
+    }
+
+    fn progress(&self) -> Progress {
+        let bytes_received = self.item.bytes_received;
+        let total_bytes = u64::try_from(self.item.total_bytes)
+            .ok()
+            .filter(|total| *total > 0);
+        let fraction = total_bytes.map(|total| (bytes_received as f64 / total as f64).min(1.0));
+        let eta = total_bytes
+            .zip(self.bytes_per_second.filter(|speed| *speed > 0.0))
+            .map(|(total, speed)| {
+                let remaining = total.saturating_sub(bytes_received) as f64;
+                Duration::from_secs_f64(remaining / speed)
+            });
+        Progress {
+            bytes_received,
+            total_bytes,
+            fraction,
+            bytes_per_second: self.bytes_per_second,
+            eta,
+        }
+    }
+}
+
+/// A [`Stream`] of [`StateTransition`]s.
+#[must_use = "streams do nothing unless polled"]
+pub struct StateTransitions(mpsc::UnboundedReceiver<StateTransition>);
+
+impl Stream for StateTransitions {
+    type Item = StateTransition;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<StateTransition>> {
+        Pin::new(&mut self.0).poll_next(cx)
+    }
+}
+
+/// Keeps the [`DownloadItem`]s matching a query up to date.
+///
+/// The browser does not emit `onChanged` events while bytes are being received,
+/// so call [`DownloadTracker::refresh`] periodically to update the progress
+/// of running downloads.
+pub struct DownloadTracker<B = BrowserBackend> {
+    backend: B,
+    downloads: Rc<RefCell<TrackedDownloads>>,
+    _listeners: Option<Listeners>,
+}
+
+struct Listeners {
+    _on_created: OnCreatedEventListener,
+    _on_changed: OnChangedEventListener,
+    _on_erased: OnErasedEventListener,
+}
+
+impl DownloadTracker {
+    /// Starts tracking the downloads that match `query`
+    /// and all downloads that are created afterwards.
+    pub async fn start(query: &DownloadQuery<'_>) -> Result<Self, Error> {
+        let downloads = Rc::new(RefCell::new(TrackedDownloads::new()));
+        // Listen before searching, so no change gets lost in between.
+        let _on_created = super::on_created().add_listener({
+            let downloads = Rc::clone(&downloads);
+            move |item| {
+                downloads.borrow_mut().insert(item, now());
+            }
+        });
+        let _on_changed = super::on_changed().add_listener({
+            let downloads = Rc::clone(&downloads);
+            move |delta| {
+                downloads.borrow_mut().apply(&delta, now());
+            }
+        });
+        let _on_erased = super::on_erased().add_listener({
+            let downloads = Rc::clone(&downloads);
+            move |id| {
+                downloads.borrow_mut().remove(id);
+            }
+        });
+        let tracker = Self {
+            backend: BrowserBackend,
+            downloads,
+            _listeners: Some(Listeners {
+                _on_created,
+                _on_changed,
+                _on_erased,
+            }),
+        };
+        tracker.track(query).await?;
+        Ok(tracker)
+    }
+}
+
+impl<B: DownloadsBackend> DownloadTracker<B> {
+    /// Starts tracking the downloads of `backend` that match `query`.
+    ///
+    /// Unlike [`DownloadTracker::start`], the tracker is not notified about
+    /// changes or new downloads, they are only picked up by [`refresh`](Self::refresh).
+    pub async fn start_with(backend: B, query: &DownloadQuery<'_>) -> Result<Self, Error> {
+        let tracker = Self {
+            backend,
+            downloads: Rc::default(),
+            _listeners: None,
+        };
+        tracker.track(query).await?;
+        Ok(tracker)
+    }
+
+    async fn track(&self, query: &DownloadQuery<'_>) -> Result<(), Error> {
+        let items = self.backend.search_downloads(query).await?;
+        let mut downloads = self.downloads.borrow_mut();
+        let now = self.backend.now().as_millis();
+        for item in items {
+            // Items that have been reported by a listener in the meantime are more recent.
+            if downloads.get(item.id).is_none() {
+                downloads.insert(item, now);
+            }
+        }
+        Ok(())
+    }
+
+    /// Updates the tracked downloads that are in progress.
+    ///
+    /// Downloads that did not match the query are not picked up.
+    pub async fn refresh(&self) -> Result<(), Error> {
+        let mut running: Vec<_> = self
+            .downloads
+            .borrow()
+            .items()
+            .filter(|item| item.state == State::InProgress)
+            .map(|item| item.id)
+            .collect();
+        if running.is_empty() {
+            return Ok(());
+        }
+        let query = DownloadQuery::default().state(State::InProgress).limit(0);
+        let items = self.backend.search_downloads(&query).await?;
+        {
+            let mut downloads = self.downloads.borrow_mut();
+            let now = self.backend.now().as_millis();
+            for item in items {
+                if let Some(index) = running.iter().position(|id| *id == item.id) {
+                    running.swap_remove(index);
+                    downloads.insert(item, now);
+                }
+            }
+        }
+        // The remaining downloads are not in progress anymore.
+        for id in running {
+            let query = DownloadQuery::default().id(id);
+            let item = self.backend.search_downloads(&query).await?.pop();
+            let mut downloads = self.downloads.borrow_mut();
+            match item {
+                Some(item) => {
+                    downloads.insert(item, self.backend.now().as_millis());
+                }
+                // Erased in the meantime.
+                None => {
+                    downloads.remove(id);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    pub fn get(&self, id: DownloadId) -> Option<DownloadItem> {
+        self.downloads.borrow().get(id).cloned()
+    }
+
+    pub fn items(&self) -> Vec<DownloadItem> {
+        self.downloads.borrow().items().cloned().collect()
+    }
+
+    pub fn progress(&self, id: DownloadId) -> Option<Progress> {
+        self.downloads.borrow().progress(id)
+    }
+
+    /// Returns a stream of all subsequent state transitions,
+    /// e.g. from [`State::InProgress`] to [`State::Complete`].
+    pub fn transitions(&self) -> StateTransitions {
+        self.downloads.borrow_mut().transitions()
+    }
+}
+
+fn now() -> f64 {
+    js_sys::Date::now()
+}
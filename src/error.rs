@@ -12,6 +12,51 @@ pub enum Error {
     JsonSerialization(serde_json::Error),
     #[error("Unable to convert JS value to an JS object")]
     ObjectConversion,
+    #[error("Could not establish connection: the receiving end does not exist")]
+    NoReceiver,
+    #[error("Stored schema version {found} is newer than the supported version {supported}")]
+    UnsupportedSchemaVersion { found: u32, supported: u32 },
+    #[error("No migration registered for schema version {0}")]
+    MissingMigration(u32),
+    #[error("No response within the timeout")]
+    Timeout,
+    #[error("Remote error: {0}")]
+    Remote(crate::rpc::RemoteError),
+    #[error("Received the response to request {found} instead of {expected}")]
+    UnexpectedResponseId { expected: u64, found: u64 },
+    #[error("Not a Netscape bookmark file")]
+    InvalidBookmarkFile,
+    #[error("Backend error: {0}")]
+    Backend(String),
+    #[error("{namespace}.{event} is not supported by this browser")]
+    UnsupportedEvent {
+        namespace: &'static str,
+        event: &'static str,
+    },
+    #[error(transparent)]
+    InvalidWindowState(#[from] crate::windows::InvalidWindowStateError),
+    #[error(transparent)]
+    InvalidWindowType(#[from] crate::tabs::InvalidWindowTypeError),
+}
+
+/// Part of the message both Chrome and Firefox use to reject a message
+/// that no listener was registered for.
+const NO_RECEIVER_MESSAGE: &str = "Receiving end does not exist";
+
+impl Error {
+    /// Converts the rejection of a `sendMessage` promise,
+    /// distinguishing a missing receiver from any other failure.
+    pub(crate) fn from_send_message(err: JsValue) -> Self {
+        let is_no_receiver = js_sys::Reflect::get(&err, &JsValue::from_str("message"))
+            .ok()
+            .and_then(|message| message.as_string())
+            .is_some_and(|message| message.contains(NO_RECEIVER_MESSAGE));
+        if is_no_receiver {
+            Self::NoReceiver
+        } else {
+            Self::Js(err)
+        }
+    }
 }
 
 impl From<JsValue> for Error {
@@ -3,17 +3,20 @@ use web_extensions_sys as sys;
 
 // Adapted from https://github.com/rustwasm/gloo/blob/2c9e776701ecb90c53e62dec1abd19c2b70e47c7/crates/events/src/lib.rs#L232-L582
 #[must_use = "event listener will never be called after being dropped"]
-pub struct EventListener<'a, F: ?Sized> {
-    target: &'a sys::EventTarget,
+pub struct EventListener<F: ?Sized> {
+    target: sys::EventTarget,
     callback: Option<Closure<F>>,
 }
 
-impl<'a, F> EventListener<'a, F>
+impl<F> EventListener<F>
 where
     F: ?Sized + WasmClosure,
 {
     #[inline]
-    pub(crate) fn raw_new(target: &'a sys::EventTarget, callback: Closure<F>) -> Self {
+    pub(crate) fn raw_new(target: &sys::EventTarget, callback: Closure<F>) -> Self {
+        // Holding a handle of our own instead of borrowing the target
+        // allows the listener to outlive the value it was registered on.
+        let target = JsValue::clone(target).unchecked_into::<sys::EventTarget>();
         target.add_listener(callback.as_ref().unchecked_ref());
         Self {
             target,
@@ -33,7 +36,7 @@ where
     }
 }
 
-impl<F: ?Sized> Drop for EventListener<'_, F> {
+impl<F: ?Sized> Drop for EventListener<F> {
     #[inline]
     fn drop(&mut self) {
         if let Some(callback) = &self.callback {
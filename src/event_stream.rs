@@ -0,0 +1,159 @@
+use crate::event_listener::EventListener;
+use futures_channel::mpsc;
+use futures_core::Stream;
+use std::{
+    any::Any,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use wasm_bindgen::{closure::WasmClosure, prelude::*};
+use web_extensions_sys as sys;
+
+/// A [`Stream`] of the events emitted by an event target.
+///
+/// The underlying listener is removed as soon as the stream is dropped.
+#[must_use = "streams do nothing unless polled"]
+pub struct EventStream<T> {
+    // Type-erased `EventListener` that is removed when dropped.
+    _listener: Box<dyn Any>,
+    receiver: mpsc::UnboundedReceiver<T>,
+}
+
+/// Sending half of an [`EventStream`], used inside of the listener.
+pub(crate) struct EventSender<T>(mpsc::UnboundedSender<T>);
+
+impl<T> EventSender<T> {
+    pub(crate) fn send(&self, event: T) {
+        // The stream has been dropped if sending fails,
+        // so there is nobody left who is interested in the event.
+        let _ = self.0.unbounded_send(event);
+    }
+}
+
+impl<T> EventStream<T> {
+    pub(crate) fn raw_new<F>(
+        target: &sys::EventTarget,
+        make_callback: impl FnOnce(EventSender<T>) -> Closure<F>,
+    ) -> Self
+    where
+        F: ?Sized + WasmClosure + 'static,
+    {
+        let (sender, receiver) = mpsc::unbounded();
+        let listener = EventListener::raw_new(target, make_callback(EventSender(sender)));
+        Self {
+            _listener: Box::new(listener),
+            receiver,
+        }
+    }
+
+    /// Like [`raw_new`](Self::raw_new), but the stream ends as soon as `end` emits an event.
+    pub(crate) fn raw_new_until<F>(
+        target: &sys::EventTarget,
+        end: &sys::EventTarget,
+        make_callback: impl FnOnce(EventSender<T>) -> Closure<F>,
+    ) -> Self
+    where
+        F: ?Sized + WasmClosure + 'static,
+        T: 'static,
+    {
+        let (sender, receiver) = mpsc::unbounded();
+        let end_sender = sender.clone();
+        let end_listener = EventListener::raw_new(
+            end,
+            Closure::<dyn FnMut()>::new(move || end_sender.close_channel()),
+        );
+        let listener = EventListener::raw_new(target, make_callback(EventSender(sender)));
+        Self {
+            _listener: Box::new((listener, end_listener)),
+            receiver,
+        }
+    }
+
+    /// Ends the stream after the events that have already been received.
+    pub(crate) fn close(&mut self) {
+        self.receiver.close();
+    }
+
+    /// Waits for the next event.
+    pub async fn next_event(&mut self) -> T {
+        std::future::poll_fn(|cx| Pin::new(&mut self.receiver).poll_next(cx))
+            .await
+            .expect("the listener is registered as long as the stream exists")
+    }
+}
+
+impl<T> Stream for EventStream<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        Pin::new(&mut self.receiver).poll_next(cx)
+    }
+}
+
+/// Defines the wrapper of an event target together with its listener handle,
+/// `add_listener`, `stream` and `next_event`.
+///
+/// The block converts the arguments the browser passes to the listener
+/// into the arguments of the Rust listener.
+/// Events it returns `None` for are skipped, because panicking inside of
+/// a JS callback would abort the whole wasm instance.
+macro_rules! define_event {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident, $listener:ident;
+        fn($($js_arg:ident: $js_ty:ty),*) -> ($($arg:ident: $ty:ty),*) $convert:block
+    ) => {
+        $(#[$meta])*
+        pub struct $name(::web_extensions_sys::EventTarget);
+
+        pub struct $listener(
+            $crate::event_listener::EventListener<dyn FnMut($($js_ty),*)>,
+        );
+
+        impl $listener {
+            pub fn forget(self) {
+                self.0.forget()
+            }
+        }
+
+        #[allow(unused_parens)]
+        impl $name {
+            pub fn add_listener<L>(&self, mut listener: L) -> $listener
+            where
+                L: FnMut($($ty),*) + 'static,
+            {
+                let callback = ::wasm_bindgen::closure::Closure::<dyn FnMut($($js_ty),*)>::new(
+                    move |$($js_arg: $js_ty),*| {
+                        if let Some(($($arg),*)) = convert($($js_arg),*) {
+                            listener($($arg),*)
+                        }
+                    },
+                );
+                $listener($crate::event_listener::EventListener::raw_new(&self.0, callback))
+            }
+
+            /// Returns a stream of all subsequent events.
+            pub fn stream(&self) -> $crate::EventStream<($($ty),*)> {
+                $crate::EventStream::raw_new(&self.0, |sender| {
+                    ::wasm_bindgen::closure::Closure::<dyn FnMut($($js_ty),*)>::new(
+                        move |$($js_arg: $js_ty),*| {
+                            if let Some(event) = convert($($js_arg),*) {
+                                sender.send(event)
+                            }
+                        },
+                    )
+                })
+            }
+
+            /// Waits for the next event.
+            pub async fn next_event(&self) -> ($($ty),*) {
+                self.stream().next_event().await
+            }
+        }
+
+        #[allow(unused_parens)]
+        fn convert($($js_arg: $js_ty),*) -> Option<($($ty),*)> $convert
+    };
+}
+
+pub(crate) use define_event;
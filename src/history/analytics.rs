@@ -0,0 +1,237 @@
+use super::{HistoryItem, TransitionType, VisitItem};
+use crate::{timestamp::civil_from_days, Timestamp};
+use std::collections::{BTreeMap, HashMap};
+
+const MS_PER_DAY: f64 = 24.0 * 60.0 * 60.0 * 1000.0;
+
+/// Two-level public suffixes under which domains are commonly registered.
+///
+/// This is a small excerpt of the [Public Suffix List](https://publicsuffix.org/),
+/// domains under other suffixes are grouped by their last two labels.
+const MULTI_LABEL_SUFFIXES: &[&str] = &[
+    "ac.uk",
+    "co.uk",
+    "gov.uk",
+    "org.uk",
+    "com.au",
+    "net.au",
+    "org.au",
+    "co.jp",
+    "ne.jp",
+    "or.jp",
+    "co.nz",
+    "co.in",
+    "co.kr",
+    "co.za",
+    "com.br",
+    "com.cn",
+    "com.mx",
+    "com.sg",
+    "com.tr",
+    "github.io",
+];
+
+const NETWORK_SCHEMES: &[&str] = &["http", "https", "ws", "wss", "ftp"];
+
+/// Returns the registrable domain of a URL, e.g. `example.co.uk` for
+/// `https://www.shop.example.co.uk/cart`.
+///
+/// IP addresses and single-label hosts like `localhost` are returned as they are.
+/// Returns `None` for URLs that are not loaded from the network,
+/// e.g. `file:///tmp/a.txt` or `chrome://settings`.
+pub fn registrable_domain(url: &str) -> Option<String> {
+    let (scheme, rest) = url.split_once("://")?;
+    if !NETWORK_SCHEMES.contains(&scheme.to_ascii_lowercase().as_str()) {
+        return None;
+    }
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host_and_port = authority.rsplit('@').next().unwrap_or_default();
+    let host = match host_and_port.strip_prefix('[') {
+        // IPv6 address
+        Some(ipv6) => return ipv6.split(']').next().map(str::to_ascii_lowercase),
+        None => host_and_port.split(':').next().unwrap_or_default(),
+    };
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+    if host.is_empty() {
+        return None;
+    }
+    if host.split('.').all(|label| label.parse::<u8>().is_ok()) {
+        return Some(host);
+    }
+    let labels: Vec<_> = host.split('.').collect();
+    let suffix_labels = match labels.len() {
+        0..=2 => return Some(host),
+        n if MULTI_LABEL_SUFFIXES.contains(&labels[n - 2..].join(".").as_str()) => 2,
+        _ => 1,
+    };
+    let start = labels.len().saturating_sub(suffix_labels + 1);
+    Some(labels[start..].join("."))
+}
+
+/// Aggregated statistics of all history items of a domain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DomainSummary {
+    /// The registrable domain.
+    pub domain: String,
+
+    /// The number of distinct pages.
+    pub pages: usize,
+
+    /// The total number of visits of all pages.
+    pub visit_count: usize,
+
+    /// The total number of typed visits of all pages.
+    pub typed_count: usize,
+
+    /// The most recent visit of any page.
+    pub last_visit_time: Option<Timestamp>,
+}
+
+/// Groups history items by the [registrable domain](registrable_domain) of their URL.
+///
+/// The summaries are sorted by descending visit count.
+pub fn group_by_domain<'a>(items: impl IntoIterator<Item = &'a HistoryItem>) -> Vec<DomainSummary> {
+    let mut domains = HashMap::<String, DomainSummary>::new();
+    for item in items {
+        let Some(domain) = item.url.as_deref().and_then(registrable_domain) else {
+            continue;
+        };
+        let summary = domains
+            .entry(domain)
+            .or_insert_with_key(|domain| DomainSummary {
+                domain: domain.clone(),
+                pages: 0,
+                visit_count: 0,
+                typed_count: 0,
+                last_visit_time: None,
+            });
+        summary.pages += 1;
+        summary.visit_count += item.visit_count.unwrap_or_default();
+        summary.typed_count += item.typed_count.unwrap_or_default();
+        summary.last_visit_time = summary.last_visit_time.max(item.last_visit_time);
+    }
+    let mut summaries: Vec<_> = domains.into_values().collect();
+    summaries.sort_by(|a, b| {
+        b.visit_count
+            .cmp(&a.visit_count)
+            .then_with(|| a.domain.cmp(&b.domain))
+    });
+    summaries
+}
+
+/// A calendar day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Day {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl Day {
+    /// Returns the day of a point in time.
+    ///
+    /// The `utc_offset_minutes` shift the time into the local time zone,
+    /// e.g. `60` for UTC+1. Note that JavaScript's `Date.getTimezoneOffset()`
+    /// returns the negated offset.
+    pub fn from_timestamp(timestamp: Timestamp, utc_offset_minutes: i32) -> Self {
+        let local = timestamp.as_millis() + f64::from(utc_offset_minutes) * 60_000.0;
+        let (year, month, day) = civil_from_days((local / MS_PER_DAY).floor() as i64);
+        Self {
+            year: year as i32,
+            month,
+            day,
+        }
+    }
+}
+
+/// Counts the visits per day, e.g. of the visit times of [`VisitItem`]s.
+///
+/// See [`Day::from_timestamp`] for `utc_offset_minutes`.
+pub fn visits_per_day(
+    visit_times: impl IntoIterator<Item = Timestamp>,
+    utc_offset_minutes: i32,
+) -> BTreeMap<Day, usize> {
+    let mut histogram = BTreeMap::new();
+    for time in visit_times {
+        *histogram
+            .entry(Day::from_timestamp(time, utc_offset_minutes))
+            .or_default() += 1;
+    }
+    histogram
+}
+
+fn age_millis(time: Timestamp, now: Timestamp) -> f64 {
+    now.as_millis() - time.as_millis()
+}
+
+/// The weight of a visit by its age, similar to Firefox' frecency buckets.
+fn recency_weight(age_millis: f64) -> f64 {
+    match age_millis / MS_PER_DAY {
+        days if days <= 4.0 => 100.0,
+        days if days <= 14.0 => 70.0,
+        days if days <= 31.0 => 50.0,
+        days if days <= 90.0 => 30.0,
+        _ => 10.0,
+    }
+}
+
+/// The bonus of a visit by how the user got to the page.
+fn transition_bonus(transition: TransitionType) -> f64 {
+    match transition {
+        TransitionType::Typed => 2.0,
+        TransitionType::AutoBookmark => 1.4,
+        TransitionType::Link
+        | TransitionType::Generated
+        | TransitionType::Keyword
+        | TransitionType::FormSubmit
+        | TransitionType::AutoToplevel
+        | TransitionType::Other => 1.0,
+        TransitionType::ManualSubframe => 0.5,
+        TransitionType::AutoSubframe
+        | TransitionType::KeywordGenerated
+        | TransitionType::Reload => 0.0,
+    }
+}
+
+/// Computes the frecency of a page from all its visits,
+/// which rates both frequent and recent visits high.
+///
+/// Typed and bookmarked visits count more than following links,
+/// reloads and automatic subframe navigations do not count at all.
+pub fn frecency(visits: &[VisitItem], now: Timestamp) -> f64 {
+    visits
+        .iter()
+        .map(|visit| {
+            let age = visit
+                .visit_time
+                .map_or(f64::INFINITY, |time| age_millis(time, now));
+            recency_weight(age) * transition_bonus(visit.transition)
+        })
+        .sum()
+}
+
+/// Estimates the frecency of a page without fetching its visits,
+/// weighting all visits by the most recent one.
+pub fn item_frecency(item: &HistoryItem, now: Timestamp) -> f64 {
+    let age = item
+        .last_visit_time
+        .map_or(f64::INFINITY, |time| age_millis(time, now));
+    let visits = item.visit_count.unwrap_or_default() as f64;
+    let typed = item.typed_count.unwrap_or_default() as f64;
+    // Typed visits are part of the visit count and get twice the weight.
+    recency_weight(age) * (visits + typed)
+}
+
+/// Returns the `limit` items with the highest [`item_frecency`].
+pub fn top_sites(items: &[HistoryItem], now: Timestamp, limit: usize) -> Vec<&HistoryItem> {
+    let mut ranked: Vec<_> = items
+        .iter()
+        .map(|item| (item_frecency(item, now), item))
+        .collect();
+    ranked.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+    ranked
+        .into_iter()
+        .take(limit)
+        .map(|(_, item)| item)
+        .collect()
+}
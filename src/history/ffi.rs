@@ -0,0 +1,31 @@
+//! Bindings of the methods and events that `web_extensions_sys::History` does not cover (yet).
+
+use js_sys::Object;
+use wasm_bindgen::prelude::*;
+use web_extensions_sys as sys;
+
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type History;
+
+    #[wasm_bindgen(catch, method, js_name = addUrl)]
+    pub(crate) async fn add_url(this: &History, details: &Object) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, method, js_name = deleteAll)]
+    pub(crate) async fn delete_all(this: &History) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, method, js_name = deleteRange)]
+    pub(crate) async fn delete_range(this: &History, range: &Object) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, method, js_name = deleteUrl)]
+    pub(crate) async fn delete_url(this: &History, details: &Object) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, method, js_name = getVisits)]
+    pub(crate) async fn get_visits(this: &History, details: &Object) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(method, getter, js_name = onVisited)]
+    pub(crate) fn on_visited(this: &History) -> sys::EventTarget;
+
+    #[wasm_bindgen(method, getter, js_name = onVisitRemoved)]
+    pub(crate) fn on_visit_removed(this: &History) -> sys::EventTarget;
+}
@@ -0,0 +1,26 @@
+use super::prelude::*;
+
+/// <https://developer.chrome.com/docs/extensions/reference/history/#type-HistoryItem>
+///
+/// An object encapsulating one result of a history query.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryItem {
+    /// Unique identifier.
+    pub id: String,
+
+    /// When this page was last loaded.
+    pub last_visit_time: Option<Timestamp>,
+
+    /// The title of the page when it was last loaded.
+    pub title: Option<String>,
+
+    /// The number of times the user has navigated to this page by typing in the address.
+    pub typed_count: Option<usize>,
+
+    /// The URL of the page.
+    pub url: Option<String>,
+
+    /// The number of times the user has visited the page.
+    pub visit_count: Option<usize>,
+}
@@ -0,0 +1,103 @@
+//! Wrapper for the [`chrome.history` API](https://developer.chrome.com/docs/extensions/reference/history/).
+
+pub(crate) mod prelude {
+    pub(crate) use crate::event_stream::define_event;
+    pub(crate) use crate::util::{
+        js_from_serde, object_from_js, serde_from_js, serde_from_js_result,
+    };
+    pub use crate::{Error, Timestamp};
+    pub use serde::{Deserialize, Serialize};
+    pub use web_extensions_sys as sys;
+
+    pub fn history() -> sys::History {
+        crate::browser().api().history()
+    }
+
+    pub(crate) fn history_ffi() -> super::ffi::History {
+        use wasm_bindgen::JsCast;
+        history().unchecked_into()
+    }
+}
+
+use self::prelude::*;
+
+mod analytics;
+mod ffi;
+mod history_item;
+mod on_visit_removed;
+mod on_visited;
+mod pages;
+mod query;
+mod visit_item;
+
+pub use self::{
+    analytics::*, history_item::*, on_visit_removed::*, on_visited::*, pages::*, query::*,
+    visit_item::*,
+};
+
+#[derive(Serialize)]
+struct UrlDetails<'a> {
+    url: &'a str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Range {
+    start_time: Timestamp,
+    end_time: Timestamp,
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/history/#method-search>
+pub async fn search(query: &Query<'_>) -> Result<Vec<HistoryItem>, Error> {
+    let js_query = js_from_serde(query)?;
+    let js_value = history().search(object_from_js(&js_query)?).await;
+    serde_from_js(js_value)
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/history/#method-getVisits>
+pub async fn get_visits(url: &str) -> Result<Vec<VisitItem>, Error> {
+    let js_details = js_from_serde(&UrlDetails { url })?;
+    let result = history_ffi().get_visits(object_from_js(&js_details)?).await;
+    serde_from_js_result(result)
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/history/#method-addUrl>
+///
+/// Adds a visit to the URL with a transition type of "link".
+pub async fn add_url(url: &str) -> Result<(), Error> {
+    let js_details = js_from_serde(&UrlDetails { url })?;
+    history_ffi().add_url(object_from_js(&js_details)?).await?;
+    Ok(())
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/history/#method-deleteUrl>
+///
+/// Removes all occurrences of the URL from the history.
+pub async fn delete_url(url: &str) -> Result<(), Error> {
+    let js_details = js_from_serde(&UrlDetails { url })?;
+    history_ffi()
+        .delete_url(object_from_js(&js_details)?)
+        .await?;
+    Ok(())
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/history/#method-deleteRange>
+///
+/// Removes all items within the specified date range.
+/// Pages will not be removed unless all visits fall within the range.
+pub async fn delete_range(start_time: Timestamp, end_time: Timestamp) -> Result<(), Error> {
+    let js_range = js_from_serde(&Range {
+        start_time,
+        end_time,
+    })?;
+    history_ffi()
+        .delete_range(object_from_js(&js_range)?)
+        .await?;
+    Ok(())
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/history/#method-deleteAll>
+pub async fn delete_all() -> Result<(), Error> {
+    history_ffi().delete_all().await?;
+    Ok(())
+}
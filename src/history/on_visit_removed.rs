@@ -0,0 +1,25 @@
+use super::prelude::*;
+use wasm_bindgen::JsValue;
+
+/// <https://developer.chrome.com/docs/extensions/reference/history/#event-onVisitRemoved>
+pub fn on_visit_removed() -> OnVisitRemoved {
+    OnVisitRemoved(history_ffi().on_visit_removed())
+}
+
+define_event! {
+    /// <https://developer.chrome.com/docs/extensions/reference/history/#event-onVisitRemoved>
+    pub struct OnVisitRemoved, OnVisitRemovedEventListener;
+    fn(info: JsValue) -> (info: RemovedInfo) {
+        serde_from_js(info).ok()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemovedInfo {
+    /// `true` if all history was removed.
+    /// If `true`, then `urls` will be empty.
+    pub all_history: bool,
+
+    pub urls: Option<Vec<String>>,
+}
@@ -0,0 +1,15 @@
+use super::{prelude::*, HistoryItem};
+use wasm_bindgen::JsValue;
+
+/// <https://developer.chrome.com/docs/extensions/reference/history/#event-onVisited>
+pub fn on_visited() -> OnVisited {
+    OnVisited(history_ffi().on_visited())
+}
+
+define_event! {
+    /// <https://developer.chrome.com/docs/extensions/reference/history/#event-onVisited>
+    pub struct OnVisited, OnVisitedEventListener;
+    fn(item: JsValue) -> (item: HistoryItem) {
+        serde_from_js(item).ok()
+    }
+}
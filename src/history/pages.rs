@@ -0,0 +1,100 @@
+use super::{prelude::*, HistoryItem, Query};
+use crate::backend::{BrowserBackend, HistoryBackend};
+use std::collections::HashSet;
+
+const DEFAULT_MAX_RESULTS: usize = 100;
+
+/// Walks through all history items matching a query, page by page.
+///
+/// `search` returns at most `max_results` items, the most recently visited first.
+/// Each subsequent page is requested with the `end_time` narrowed
+/// to the oldest visit of the previous page, until a page is not full.
+///
+/// Note that `start_time` defaults to 24 hours in the past,
+/// set it to `Some(Timestamp::UNIX_EPOCH)` to walk the complete history.
+#[derive(Debug, Clone)]
+pub struct HistoryPages<'a> {
+    query: Query<'a>,
+    seen: HashSet<String>,
+    done: bool,
+}
+
+impl<'a> HistoryPages<'a> {
+    pub fn new(query: Query<'a>) -> Self {
+        Self {
+            query,
+            seen: HashSet::new(),
+            done: false,
+        }
+    }
+
+    /// Returns the query for the next page or `None` if all pages have been fetched.
+    pub fn query(&self) -> Option<&Query<'a>> {
+        (!self.done).then_some(&self.query)
+    }
+
+    /// Consumes the result of [`query`](Self::query)
+    /// and returns the items that have not been seen on a previous page.
+    pub fn advance(&mut self, page: Vec<HistoryItem>) -> Vec<HistoryItem> {
+        let max_results = self.query.max_results.unwrap_or(DEFAULT_MAX_RESULTS);
+        let is_full = page.len() >= max_results;
+        let oldest = page.iter().filter_map(|item| item.last_visit_time).min();
+        let new_items: Vec<_> = page
+            .into_iter()
+            .filter(|item| self.seen.insert(item.id.clone()))
+            .collect();
+        let end_time = oldest.filter(|_| is_full).map(|oldest| {
+            // Visit times have fractional milliseconds, so round up
+            // to not skip items visited within the same millisecond.
+            // They are seen again on the next page, so a page without
+            // anything new continues right before its oldest visit.
+            if new_items.is_empty() {
+                oldest
+            } else {
+                Timestamp::from_millis(oldest.as_millis().ceil())
+            }
+        });
+        match end_time {
+            Some(end_time) if !new_items.is_empty() || self.query.end_time != Some(end_time) => {
+                self.query.end_time = Some(end_time);
+            }
+            _ => self.done = true,
+        }
+        new_items
+    }
+
+    /// Fetches the next page, or returns `None` if all pages have been fetched.
+    pub async fn next_page(&mut self) -> Result<Option<Vec<HistoryItem>>, Error> {
+        self.next_page_with(&BrowserBackend).await
+    }
+
+    /// Like [`next_page`](Self::next_page), but searches the history of `backend`.
+    pub async fn next_page_with(
+        &mut self,
+        backend: &impl HistoryBackend,
+    ) -> Result<Option<Vec<HistoryItem>>, Error> {
+        let Some(query) = self.query() else {
+            return Ok(None);
+        };
+        let page = backend.search_history(query).await?;
+        Ok(Some(self.advance(page)))
+    }
+}
+
+/// Fetches all history items matching the query, see [`HistoryPages`].
+pub async fn search_all(query: Query<'_>) -> Result<Vec<HistoryItem>, Error> {
+    search_all_with(&BrowserBackend, query).await
+}
+
+/// Like [`search_all`], but searches the history of `backend`.
+pub async fn search_all_with(
+    backend: &impl HistoryBackend,
+    query: Query<'_>,
+) -> Result<Vec<HistoryItem>, Error> {
+    let mut pages = HistoryPages::new(query);
+    let mut items = Vec::new();
+    while let Some(page) = pages.next_page_with(backend).await? {
+        items.extend(page);
+    }
+    Ok(items)
+}
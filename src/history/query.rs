@@ -0,0 +1,33 @@
+use super::prelude::*;
+
+/// <https://developer.chrome.com/docs/extensions/reference/history/#type-search-query>
+#[derive(Default, Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Query<'a> {
+    /// A free-text query to the history service.
+    ///
+    /// Leave empty to retrieve all pages.
+    pub text: &'a str,
+
+    /// Limit results to those visited before this date.
+    pub end_time: Option<Timestamp>,
+
+    /// The maximum number of results to retrieve.
+    ///
+    /// Defaults to 100.
+    pub max_results: Option<usize>,
+
+    /// Limit results to those visited after this date.
+    ///
+    /// If not specified, this defaults to 24 hours in the past.
+    pub start_time: Option<Timestamp>,
+}
+
+impl<'a> From<&'a str> for Query<'a> {
+    fn from(q: &'a str) -> Self {
+        Self {
+            text: q,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use super::prelude::*;
+
+/// <https://developer.chrome.com/docs/extensions/reference/history/#type-VisitItem>
+///
+/// An object encapsulating one visit to a URL.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VisitItem {
+    /// The unique identifier for the corresponding [`HistoryItem`](super::HistoryItem).
+    pub id: String,
+
+    /// The unique identifier for this visit.
+    pub visit_id: String,
+
+    /// When this visit occurred.
+    pub visit_time: Option<Timestamp>,
+
+    /// The visit ID of the referrer.
+    pub referring_visit_id: String,
+
+    /// The transition type for this visit from its referrer.
+    pub transition: TransitionType,
+
+    /// `true` if the visit originated on this device,
+    /// `false` if it was synced from a different device.
+    pub is_local: Option<bool>,
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/history/#type-TransitionType>
+///
+/// The transition type for a visit from its referrer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransitionType {
+    /// The user arrived at this page by clicking a link on another page.
+    Link,
+    /// The user arrived at this page by typing the URL in the address bar.
+    Typed,
+    /// The user arrived at this page through a suggestion in the UI, e.g. a menu item.
+    AutoBookmark,
+    /// Subframe navigation that was not requested by the user.
+    AutoSubframe,
+    /// Subframe navigation explicitly requested by the user.
+    ManualSubframe,
+    /// The user typed in the address bar and selected an entry that did not look like a URL.
+    Generated,
+    /// The page was specified in the command line or is the start page.
+    AutoToplevel,
+    /// The user filled out values in a form and submitted it.
+    FormSubmit,
+    /// The user reloaded the page.
+    Reload,
+    /// The URL was retrieved from a replaceable keyword other than the default search engine.
+    Keyword,
+    /// Corresponds to a visit generated for a keyword.
+    KeywordGenerated,
+    /// Any value that is not known to this crate (yet).
+    #[serde(other, skip_serializing)]
+    Other,
+}
@@ -1,13 +1,21 @@
+mod browser;
 mod error;
 mod event_listener;
+mod event_stream;
+mod timestamp;
 mod util;
 
-pub use crate::error::*;
+pub use crate::{browser::*, error::*, event_stream::EventStream, timestamp::*};
 
+pub mod backend;
 pub mod bookmarks;
 pub mod downloads;
 pub mod history;
+pub mod rpc;
+pub mod runtime;
+pub mod storage;
 pub mod tabs;
+pub mod windows;
 
 #[cfg(feature = "firefox")]
 pub mod contextual_identities;
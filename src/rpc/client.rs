@@ -0,0 +1,106 @@
+use super::{prelude::*, timeout::timeout, Outcome, Request, RpcRequest, RpcResponse};
+use crate::{
+    runtime,
+    tabs::{self, SendMessageOptions, TabId},
+};
+use std::{cell::Cell, time::Duration};
+
+/// Where the requests of a [`Client`] are sent to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Runtime,
+    Tab {
+        tab_id: TabId,
+        frame_id: Option<u32>,
+    },
+}
+
+/// Calls requests that are handled by a [`Dispatcher`](super::Dispatcher) in another context.
+#[derive(Debug)]
+pub struct Client {
+    target: Target,
+    timeout: Option<Duration>,
+    next_id: Cell<u64>,
+}
+
+impl Client {
+    /// Sends requests to the extension's own pages and service worker,
+    /// e.g. from a content script or popup to the background.
+    pub fn runtime() -> Self {
+        Self::new(Target::Runtime)
+    }
+
+    /// Sends requests to the content scripts of a tab.
+    pub fn tab(tab_id: TabId) -> Self {
+        Self::new(Target::Tab {
+            tab_id,
+            frame_id: None,
+        })
+    }
+
+    /// Sends requests to the content script of a specific frame of a tab.
+    pub fn frame(tab_id: TabId, frame_id: u32) -> Self {
+        Self::new(Target::Tab {
+            tab_id,
+            frame_id: Some(frame_id),
+        })
+    }
+
+    fn new(target: Target) -> Self {
+        Self {
+            target,
+            timeout: None,
+            next_id: Cell::new(0),
+        }
+    }
+
+    /// Fails calls with [`Error::Timeout`] if no response arrived within `timeout`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sends a request and waits for its response.
+    ///
+    /// Errors of the handler are returned as [`Error::Remote`].
+    pub async fn call<R: Request>(&self, request: &R) -> Result<R::Response, Error> {
+        let id = self.next_id.get();
+        self.next_id.set(id.wrapping_add(1));
+        let request = RpcRequest {
+            id,
+            method: R::METHOD.to_owned(),
+            params: serde_json::to_value(request).map_err(Error::JsonSerialization)?,
+        };
+        let response = match self.timeout {
+            Some(duration) => timeout(duration, self.send(&request))
+                .await
+                .ok_or(Error::Timeout)??,
+            None => self.send(&request).await?,
+        };
+        if response.id != id {
+            return Err(Error::UnexpectedResponseId {
+                expected: id,
+                found: response.id,
+            });
+        }
+        match response.outcome {
+            Outcome::Ok(result) => {
+                serde_json::from_value(result).map_err(Error::JsonDeserialization)
+            }
+            Outcome::Err(err) => Err(Error::Remote(err)),
+        }
+    }
+
+    async fn send(&self, request: &RpcRequest) -> Result<RpcResponse, Error> {
+        match self.target {
+            Target::Runtime => runtime::send_message(request).await,
+            Target::Tab { tab_id, frame_id } => {
+                let options = SendMessageOptions {
+                    frame_id,
+                    ..Default::default()
+                };
+                tabs::send_message_with_response(tab_id, request, &options).await
+            }
+        }
+    }
+}
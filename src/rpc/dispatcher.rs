@@ -0,0 +1,74 @@
+use super::{prelude::*, Outcome, RemoteError, Request, RpcRequest, RpcResponse};
+use crate::runtime::{self, OnMessageEventListener};
+use std::{collections::HashMap, future::Future, pin::Pin, rc::Rc};
+
+type BoxFuture<T> = Pin<Box<dyn Future<Output = T>>>;
+
+type Handler = Box<dyn Fn(Value, MessageSender) -> BoxFuture<Outcome>>;
+
+/// Handles requests sent by a [`Client`](super::Client) in another context.
+#[derive(Default)]
+pub struct Dispatcher {
+    handlers: HashMap<&'static str, Handler>,
+}
+
+impl Dispatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the handler of requests of type `R`,
+    /// replacing any previous handler of the same method.
+    pub fn handle<R, F, Fut>(mut self, handler: F) -> Self
+    where
+        R: Request,
+        F: Fn(R, MessageSender) -> Fut + 'static,
+        Fut: Future<Output = Result<R::Response, RemoteError>> + 'static,
+    {
+        let handler = Rc::new(handler);
+        let handler: Handler = Box::new(move |params, sender| {
+            let handler = Rc::clone(&handler);
+            Box::pin(async move {
+                let request = match serde_json::from_value::<R>(params) {
+                    Ok(request) => request,
+                    Err(err) => return Outcome::Err(RemoteError::new(err.to_string())),
+                };
+                match handler(request, sender).await {
+                    Ok(response) => match serde_json::to_value(response) {
+                        Ok(result) => Outcome::Ok(result),
+                        Err(err) => Outcome::Err(RemoteError::new(err.to_string())),
+                    },
+                    Err(err) => Outcome::Err(err),
+                }
+            })
+        });
+        self.handlers.insert(R::METHOD, handler);
+        self
+    }
+
+    /// Handles a single request.
+    ///
+    /// Returns `None` if no handler is registered for the method of the request.
+    pub fn dispatch(
+        &self,
+        request: RpcRequest,
+        sender: MessageSender,
+    ) -> Option<impl Future<Output = RpcResponse>> {
+        let RpcRequest { id, method, params } = request;
+        let outcome = self.handlers.get(method.as_str())?(params, sender);
+        Some(async move {
+            RpcResponse {
+                id,
+                outcome: outcome.await,
+            }
+        })
+    }
+
+    /// Starts handling requests sent to this context.
+    ///
+    /// Requests of unknown methods are left to other listeners.
+    pub fn listen(self) -> OnMessageEventListener {
+        runtime::on_message()
+            .add_listener(move |request: RpcRequest, sender| self.dispatch(request, sender))
+    }
+}
@@ -0,0 +1,62 @@
+use super::prelude::*;
+use std::fmt;
+
+/// The message that is sent for every call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RpcRequest {
+    /// Identifies the call, echoed by the response.
+    pub id: u64,
+
+    /// The [`Request::METHOD`](super::Request::METHOD) of the request.
+    pub method: String,
+
+    /// The serialized request.
+    pub params: Value,
+}
+
+/// The message that is sent back for every handled call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RpcResponse {
+    /// The ID of the request.
+    pub id: u64,
+
+    #[serde(flatten)]
+    pub outcome: Outcome,
+}
+
+/// The serialized result of a call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Outcome {
+    #[serde(rename = "result")]
+    Ok(Value),
+    #[serde(rename = "error")]
+    Err(RemoteError),
+}
+
+/// An error that occurred while handling a call on the remote end.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemoteError {
+    pub message: String,
+}
+
+impl RemoteError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for RemoteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for RemoteError {}
+
+impl From<Error> for RemoteError {
+    fn from(err: Error) -> Self {
+        Self::new(err.to_string())
+    }
+}
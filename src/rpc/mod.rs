@@ -0,0 +1,56 @@
+//! Typed request/response RPC on top of extension messaging.
+//!
+//! Requests are plain serializable types that implement [`Request`].
+//! A [`Dispatcher`] in one context (e.g. the background service worker)
+//! handles them and a [`Client`] in another context (e.g. a content script or popup) calls them:
+//!
+//! ```no_run
+//! use serde::{Deserialize, Serialize};
+//! use web_extensions::rpc::{Client, Dispatcher, Request};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Add(i32, i32);
+//!
+//! impl Request for Add {
+//!     const METHOD: &'static str = "add";
+//!     type Response = i32;
+//! }
+//!
+//! // background
+//! Dispatcher::new()
+//!     .handle(|Add(a, b), _sender| async move { Ok(a + b) })
+//!     .listen()
+//!     .forget();
+//!
+//! // content script
+//! # async {
+//! let sum = Client::runtime().call(&Add(1, 2)).await?;
+//! # Ok::<_, web_extensions::Error>(())
+//! # };
+//! ```
+
+pub(crate) mod prelude {
+    pub use crate::{runtime::MessageSender, Error};
+    pub use serde::{Deserialize, Serialize};
+    pub use serde_json::Value;
+}
+
+mod client;
+mod dispatcher;
+mod envelope;
+mod timeout;
+
+pub use self::{client::*, dispatcher::*, envelope::*};
+
+use self::prelude::*;
+
+/// A request with an associated response type.
+pub trait Request: Serialize + for<'a> Deserialize<'a> + 'static {
+    /// The name the request is routed by.
+    ///
+    /// It must be unique among all requests handled by a [`Dispatcher`].
+    const METHOD: &'static str;
+
+    /// The type of a successful response.
+    type Response: Serialize + for<'a> Deserialize<'a>;
+}
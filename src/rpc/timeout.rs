@@ -0,0 +1,74 @@
+use futures_channel::oneshot;
+use std::{
+    future::Future,
+    pin::{pin, Pin},
+    task::{Context, Poll},
+    time::Duration,
+};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    // Available in windows as well as in service workers.
+    #[wasm_bindgen(js_name = setTimeout)]
+    fn set_timeout(handler: &js_sys::Function, timeout: i32) -> JsValue;
+
+    #[wasm_bindgen(js_name = clearTimeout)]
+    fn clear_timeout(id: &JsValue);
+}
+
+/// Resolves to `None` if `future` did not complete within `duration`.
+pub(crate) async fn timeout<F: Future>(duration: Duration, future: F) -> Option<F::Output> {
+    let mut future = pin!(future);
+    let mut sleep = Sleep::new(duration);
+    std::future::poll_fn(|cx| {
+        if let Poll::Ready(output) = future.as_mut().poll(cx) {
+            return Poll::Ready(Some(output));
+        }
+        if Pin::new(&mut sleep).poll(cx).is_ready() {
+            return Poll::Ready(None);
+        }
+        Poll::Pending
+    })
+    .await
+}
+
+struct Sleep {
+    id: JsValue,
+    receiver: oneshot::Receiver<()>,
+    // Keeps the Rust closure behind the timeout alive.
+    _callback: Closure<dyn FnMut()>,
+}
+
+impl Sleep {
+    fn new(duration: Duration) -> Self {
+        let (sender, receiver) = oneshot::channel();
+        let mut sender = Some(sender);
+        let callback = Closure::<dyn FnMut()>::new(move || {
+            if let Some(sender) = sender.take() {
+                let _ = sender.send(());
+            }
+        });
+        let millis = i32::try_from(duration.as_millis()).unwrap_or(i32::MAX);
+        let id = set_timeout(callback.as_ref().unchecked_ref(), millis);
+        Self {
+            id,
+            receiver,
+            _callback: callback,
+        }
+    }
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        Pin::new(&mut self.receiver).poll(cx).map(|_| ())
+    }
+}
+
+impl Drop for Sleep {
+    fn drop(&mut self) {
+        clear_timeout(&self.id);
+    }
+}
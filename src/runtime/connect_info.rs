@@ -0,0 +1,13 @@
+use super::prelude::*;
+
+/// <https://developer.chrome.com/docs/extensions/reference/runtime/#method-connect-connectInfo>
+#[derive(Debug, Default, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectInfo<'a> {
+    /// Whether the TLS channel ID will be passed into `onConnectExternal`
+    /// for the process listening for the connection event.
+    pub include_tls_channel_id: Option<bool>,
+
+    /// Will be passed into `onConnect` for processes that are listening for the connection event.
+    pub name: Option<&'a str>,
+}
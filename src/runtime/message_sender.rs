@@ -0,0 +1,33 @@
+use super::prelude::*;
+use crate::tabs::Tab;
+
+/// <https://developer.chrome.com/docs/extensions/reference/runtime/#type-MessageSender>
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageSender {
+    /// A UUID of the document that opened the connection.
+    pub document_id: Option<String>,
+
+    /// The frame that opened the connection.
+    ///
+    /// `0` for top-level frames, positive for child frames.
+    /// Only set when `tab` is set.
+    pub frame_id: Option<u32>,
+
+    /// The ID of the extension that opened the connection, if any.
+    pub id: Option<String>,
+
+    /// The origin of the page or frame that opened the connection.
+    pub origin: Option<String>,
+
+    /// The tab which opened the connection, if any.
+    ///
+    /// Only present when the connection was opened from a tab (including content scripts).
+    pub tab: Option<Tab>,
+
+    /// The TLS channel ID of the page or frame that opened the connection.
+    pub tls_channel_id: Option<String>,
+
+    /// The URL of the page or frame that opened the connection.
+    pub url: Option<String>,
+}
@@ -0,0 +1,52 @@
+//! Wrapper for the [`chrome.runtime` API](https://developer.chrome.com/docs/extensions/reference/runtime/).
+
+pub(crate) mod prelude {
+    pub(crate) use crate::event_stream::define_event;
+    pub(crate) use crate::util::{js_from_serde, object_from_js, serde_from_js};
+    pub use crate::{event_listener::EventListener, Error};
+    pub use serde::{Deserialize, Serialize};
+    pub use wasm_bindgen::closure::Closure;
+    pub use web_extensions_sys as sys;
+
+    pub fn runtime() -> sys::Runtime {
+        crate::browser().api().runtime()
+    }
+}
+
+use self::prelude::*;
+
+mod connect_info;
+mod message_sender;
+mod on_connect;
+mod on_message;
+mod port;
+
+pub use self::{connect_info::*, message_sender::*, on_connect::*, on_message::*, port::*};
+
+/// <https://developer.chrome.com/docs/extensions/reference/runtime/#method-sendMessage>
+///
+/// Sends a message to the extension's own listeners in other contexts
+/// and returns the response of the first listener that replied.
+///
+/// Returns [`Error::NoReceiver`] if no listener received the message.
+pub async fn send_message<Req, Resp>(message: &Req) -> Result<Resp, Error>
+where
+    Req: Serialize,
+    Resp: for<'a> Deserialize<'a>,
+{
+    let js_message = js_from_serde(message)?;
+    let result = runtime()
+        .send_message(None, &js_message, None)
+        .await
+        .map_err(Error::from_send_message)?;
+    serde_from_js(result)
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/runtime/#method-connect>
+///
+/// Opens a long-lived connection to the extension's own `onConnect` listeners.
+pub fn connect(info: &ConnectInfo<'_>) -> Result<Port, Error> {
+    let js_info = js_from_serde(info)?;
+    let port = runtime().connect(None, object_from_js(&js_info)?);
+    Ok(Port::new(port))
+}
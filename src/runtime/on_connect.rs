@@ -0,0 +1,16 @@
+use super::{prelude::*, Port};
+
+/// <https://developer.chrome.com/docs/extensions/reference/runtime/#event-onConnect>
+pub fn on_connect() -> OnConnect {
+    OnConnect(runtime().on_connect())
+}
+
+define_event! {
+    /// <https://developer.chrome.com/docs/extensions/reference/runtime/#event-onConnect>
+    ///
+    /// The port is disconnected as soon as the listener drops it.
+    pub struct OnConnect, OnConnectEventListener;
+    fn(port: sys::Port) -> (port: Port) {
+        Some(Port::new(port))
+    }
+}
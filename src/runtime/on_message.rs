@@ -0,0 +1,68 @@
+use super::{prelude::*, MessageSender};
+use crate::util::log_error;
+use js_sys::Function;
+use std::future::Future;
+use wasm_bindgen::JsValue;
+
+/// <https://developer.chrome.com/docs/extensions/reference/runtime/#event-onMessage>
+pub fn on_message() -> OnMessage {
+    OnMessage(runtime().on_message())
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/runtime/#event-onMessage>
+pub struct OnMessage(sys::EventTarget);
+
+pub struct OnMessageEventListener(EventListener<dyn FnMut(JsValue, JsValue, Function) -> bool>);
+
+impl OnMessageEventListener {
+    pub fn forget(self) {
+        self.0.forget()
+    }
+}
+
+impl OnMessage {
+    /// Adds a listener for messages of type `Req`.
+    ///
+    /// Messages that cannot be deserialized into `Req` are left to other listeners,
+    /// as are messages whose sender cannot be deserialized.
+    /// If the listener returns a future, its output is sent back as the response,
+    /// otherwise the message remains unanswered and another listener may reply.
+    /// A response that cannot be serialized is logged to the console
+    /// and the sender receives no value.
+    pub fn add_listener<Req, Resp, F, L>(&self, mut listener: L) -> OnMessageEventListener
+    where
+        Req: for<'a> Deserialize<'a>,
+        Resp: Serialize,
+        F: Future<Output = Resp> + 'static,
+        L: FnMut(Req, MessageSender) -> Option<F> + 'static,
+    {
+        let listener = Closure::new(
+            move |message: JsValue, sender: JsValue, send_response: Function| {
+                let Ok(message) = serde_from_js(message) else {
+                    return false;
+                };
+                let Ok(sender) = serde_from_js(sender) else {
+                    return false;
+                };
+                let Some(response) = listener(message, sender) else {
+                    return false;
+                };
+                wasm_bindgen_futures::spawn_local(async move {
+                    let js_response = match js_from_serde(&response.await) {
+                        Ok(js_response) => js_response,
+                        Err(err) => {
+                            log_error("failed to serialize the response to a message", &err);
+                            // Responding without a value at least closes the channel,
+                            // so the sender does not wait forever.
+                            JsValue::UNDEFINED
+                        }
+                    };
+                    let _ = send_response.call1(&JsValue::NULL, &js_response);
+                });
+                // Keeps the message channel open until `send_response` is called.
+                true
+            },
+        );
+        OnMessageEventListener(EventListener::raw_new(&self.0, listener))
+    }
+}
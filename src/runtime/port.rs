@@ -0,0 +1,153 @@
+use super::{prelude::*, MessageSender};
+use crate::EventStream;
+use futures_core::Stream;
+use std::{
+    cell::RefCell,
+    pin::Pin,
+    rc::Rc,
+    task::{Context, Poll},
+};
+use wasm_bindgen::{prelude::*, JsCast};
+
+#[wasm_bindgen]
+extern "C" {
+    // `web_extensions_sys::Port::post_message` does not catch the error
+    // that is thrown if the port has already been disconnected.
+    type CatchingPort;
+
+    #[wasm_bindgen(catch, method, js_name = postMessage)]
+    fn post_message(this: &CatchingPort, message: &JsValue) -> Result<(), JsValue>;
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/runtime/#type-Port>
+///
+/// A long-lived connection between two contexts.
+/// The port is disconnected as soon as it is dropped.
+pub struct Port {
+    inner: sys::Port,
+    disconnect: EventStream<()>,
+    // `None` while connected, then the error that caused the disconnect, if any.
+    disconnected: Rc<RefCell<Option<Option<JsValue>>>>,
+}
+
+impl Port {
+    pub(crate) fn new(inner: sys::Port) -> Self {
+        let disconnected = Rc::new(RefCell::new(None));
+        let disconnect = EventStream::raw_new(&inner.on_disconnect(), |sender| {
+            let disconnected = Rc::clone(&disconnected);
+            Closure::<dyn FnMut(sys::Port)>::new(move |port: sys::Port| {
+                *disconnected.borrow_mut() = Some(disconnect_error(&port));
+                sender.send(())
+            })
+        });
+        Self {
+            inner,
+            disconnect,
+            disconnected,
+        }
+    }
+
+    /// The name of the port, as specified in the call to `connect`.
+    pub fn name(&self) -> String {
+        self.inner.name()
+    }
+
+    /// The sender of the connection.
+    ///
+    /// Only present on ports passed to `onConnect` listeners
+    /// and if the sender could be deserialized.
+    pub fn sender(&self) -> Option<MessageSender> {
+        self.inner
+            .sender()
+            .and_then(|sender| serde_from_js(sender.into()).ok())
+    }
+
+    /// Sends a message to the other end of the port.
+    pub fn post_message<T>(&self, message: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let js_message = js_from_serde(message)?;
+        self.inner
+            .unchecked_ref::<CatchingPort>()
+            .post_message(&js_message)?;
+        Ok(())
+    }
+
+    /// Returns a stream of all subsequent messages received on the port.
+    ///
+    /// Messages that cannot be deserialized into `T` are yielded as errors.
+    /// The stream ends when the other end disconnects the port.
+    pub fn messages<T>(&self) -> PortMessages<T>
+    where
+        T: for<'a> Deserialize<'a> + 'static,
+    {
+        let mut stream = EventStream::raw_new_until(
+            &self.inner.on_message(),
+            &self.inner.on_disconnect(),
+            |sender| {
+                Closure::<dyn FnMut(JsValue)>::new(move |message: JsValue| {
+                    sender.send(serde_from_js(message))
+                })
+            },
+        );
+        if self.disconnected.borrow().is_some() {
+            stream.close();
+        }
+        PortMessages(stream)
+    }
+
+    /// Waits until the other end disconnected the port.
+    ///
+    /// Resolves to the error that caused the disconnect, if any,
+    /// and immediately once the port has been disconnected.
+    /// It never resolves if the port is disconnected by this end.
+    pub async fn on_disconnect(&mut self) -> Option<Error> {
+        loop {
+            if let Some(error) = &*self.disconnected.borrow() {
+                return error.clone().map(Error::Js);
+            }
+            self.disconnect.next_event().await;
+        }
+    }
+
+    /// Immediately disconnects the port.
+    pub fn disconnect(self) {
+        // Disconnecting happens on drop.
+    }
+}
+
+/// The messages received on a [`Port`], see [`Port::messages`].
+#[must_use = "streams do nothing unless polled"]
+pub struct PortMessages<T>(EventStream<Result<T, Error>>);
+
+impl<T> PortMessages<T> {
+    /// Waits for the next message.
+    ///
+    /// Returns `None` once the port has been disconnected.
+    pub async fn next_message(&mut self) -> Option<Result<T, Error>> {
+        std::future::poll_fn(|cx| Pin::new(&mut self.0).poll_next(cx)).await
+    }
+}
+
+impl<T> Stream for PortMessages<T> {
+    type Item = Result<T, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.0).poll_next(cx)
+    }
+}
+
+impl Drop for Port {
+    fn drop(&mut self) {
+        self.inner.disconnect();
+    }
+}
+
+fn disconnect_error(port: &sys::Port) -> Option<JsValue> {
+    // Firefox sets the error on the port, Chrome reports it as `lastError`.
+    js_sys::Reflect::get(port, &JsValue::from_str("error"))
+        .ok()
+        .filter(|error| !error.is_undefined() && !error.is_null())
+        .or_else(|| runtime().last_error().map(JsValue::from))
+}
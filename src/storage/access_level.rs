@@ -0,0 +1,15 @@
+use super::prelude::*;
+
+/// <https://developer.chrome.com/docs/extensions/reference/storage/#type-AccessLevel>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AccessLevel {
+    /// Only contexts originating from the extension itself.
+    #[serde(rename(serialize = "TRUSTED_CONTEXTS", deserialize = "TRUSTED_CONTEXTS"))]
+    TrustedContexts,
+    /// Contexts originating from the extension and content scripts.
+    #[serde(rename(
+        serialize = "TRUSTED_AND_UNTRUSTED_CONTEXTS",
+        deserialize = "TRUSTED_AND_UNTRUSTED_CONTEXTS"
+    ))]
+    TrustedAndUntrustedContexts,
+}
@@ -0,0 +1,32 @@
+use super::prelude::*;
+use thiserror::Error;
+
+/// The name of a storage area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AreaName {
+    #[serde(rename(serialize = "local", deserialize = "local"))]
+    Local,
+    #[serde(rename(serialize = "sync", deserialize = "sync"))]
+    Sync,
+    #[serde(rename(serialize = "session", deserialize = "session"))]
+    Session,
+    #[serde(rename(serialize = "managed", deserialize = "managed"))]
+    Managed,
+}
+
+#[derive(Debug, Error)]
+#[error("Invalid area name ('{0}'), expected 'local', 'sync', 'session' or 'managed'")]
+pub struct InvalidAreaNameError(String);
+
+impl TryFrom<String> for AreaName {
+    type Error = InvalidAreaNameError;
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        match &*s {
+            "local" => Ok(AreaName::Local),
+            "sync" => Ok(AreaName::Sync),
+            "session" => Ok(AreaName::Session),
+            "managed" => Ok(AreaName::Managed),
+            _ => Err(InvalidAreaNameError(s)),
+        }
+    }
+}
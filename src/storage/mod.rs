@@ -0,0 +1,53 @@
+//! Wrapper for the [`chrome.storage` API](https://developer.chrome.com/docs/extensions/reference/storage/).
+
+pub(crate) mod prelude {
+    pub(crate) use crate::event_stream::define_event;
+    pub(crate) use crate::util::{
+        js_from_serde, object_from_js, serde_from_js, serde_from_js_result,
+    };
+    pub use crate::Error;
+    pub use serde::{Deserialize, Serialize};
+    pub use web_extensions_sys as sys;
+
+    pub fn storage() -> sys::Storage {
+        crate::browser().api().storage()
+    }
+}
+
+use self::prelude::*;
+use wasm_bindgen::JsCast;
+
+mod access_level;
+mod area_name;
+mod on_changed;
+mod storage_area;
+mod storage_change;
+mod typed_store;
+
+pub use self::{
+    access_level::*, area_name::*, on_changed::*, storage_area::*, storage_change::*,
+    typed_store::*,
+};
+
+/// <https://developer.chrome.com/docs/extensions/reference/storage/#property-local>
+pub fn local() -> StorageArea {
+    StorageArea::new(storage().local())
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/storage/#property-sync>
+pub fn sync() -> StorageArea {
+    StorageArea::new(storage().sync())
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/storage/#property-session>
+pub fn session() -> StorageArea {
+    StorageArea::new(storage().session())
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/storage/#property-managed>
+///
+/// The managed storage area is read-only,
+/// so all modifications are rejected by the browser.
+pub fn managed() -> StorageArea {
+    StorageArea::new(storage().managed().unchecked_into())
+}
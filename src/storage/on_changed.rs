@@ -0,0 +1,15 @@
+use super::{prelude::*, AreaName, StorageChanges};
+use wasm_bindgen::JsValue;
+
+/// <https://developer.chrome.com/docs/extensions/reference/storage/#event-onChanged>
+pub fn on_changed() -> OnChanged {
+    OnChanged(storage().on_changed())
+}
+
+define_event! {
+    /// <https://developer.chrome.com/docs/extensions/reference/storage/#event-onChanged>
+    pub struct OnChanged, OnChangedEventListener;
+    fn(changes: JsValue, area_name: String) -> (changes: StorageChanges, area_name: AreaName) {
+        Some((serde_from_js(changes).ok()?, AreaName::try_from(area_name).ok()?))
+    }
+}
@@ -0,0 +1,94 @@
+use super::{prelude::*, AccessLevel};
+use js_sys::Object;
+use wasm_bindgen::{prelude::*, JsCast};
+
+#[wasm_bindgen]
+extern "C" {
+    // `web_extensions_sys` does not expose `setAccessLevel` (yet).
+    type AccessLevelStorageArea;
+
+    #[wasm_bindgen(catch, method, js_name = setAccessLevel)]
+    async fn set_access_level(
+        this: &AccessLevelStorageArea,
+        access_options: &Object,
+    ) -> Result<JsValue, JsValue>;
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/storage/#type-StorageArea>
+pub struct StorageArea(sys::StorageArea);
+
+impl StorageArea {
+    pub(crate) fn new(area: sys::StorageArea) -> Self {
+        Self(area)
+    }
+
+    /// <https://developer.chrome.com/docs/extensions/reference/storage/#method-StorageArea-get>
+    ///
+    /// The `keys` can be a single key, a list of keys,
+    /// an object with default values or `()` to get the entire contents of the storage.
+    pub async fn get<T, K>(&self, keys: &K) -> Result<T, Error>
+    where
+        T: for<'a> Deserialize<'a>,
+        K: Serialize + ?Sized,
+    {
+        let js_keys = js_from_serde(keys)?;
+        let result = self.0.get(&js_keys).await;
+        serde_from_js_result(result)
+    }
+
+    /// <https://developer.chrome.com/docs/extensions/reference/storage/#method-StorageArea-set>
+    ///
+    /// The `items` must serialize to an object of key/value pairs.
+    pub async fn set<T>(&self, items: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let js_items = js_from_serde(items)?;
+        self.0.set(object_from_js(&js_items)?).await?;
+        Ok(())
+    }
+
+    /// <https://developer.chrome.com/docs/extensions/reference/storage/#method-StorageArea-remove>
+    pub async fn remove<K>(&self, keys: &K) -> Result<(), Error>
+    where
+        K: Serialize + ?Sized,
+    {
+        let js_keys = js_from_serde(keys)?;
+        self.0.remove(&js_keys).await?;
+        Ok(())
+    }
+
+    /// <https://developer.chrome.com/docs/extensions/reference/storage/#method-StorageArea-clear>
+    pub async fn clear(&self) -> Result<(), Error> {
+        self.0.clear().await?;
+        Ok(())
+    }
+
+    /// <https://developer.chrome.com/docs/extensions/reference/storage/#method-StorageArea-getBytesInUse>
+    ///
+    /// Pass `()` to get the total usage of the storage area.
+    pub async fn get_bytes_in_use<K>(&self, keys: &K) -> Result<u64, Error>
+    where
+        K: Serialize + ?Sized,
+    {
+        let js_keys = js_from_serde(keys)?;
+        let result = self.0.get_bytes_in_use(&js_keys).await;
+        serde_from_js_result(result)
+    }
+
+    /// <https://developer.chrome.com/docs/extensions/reference/storage/#method-StorageArea-setAccessLevel>
+    pub async fn set_access_level(&self, access_level: AccessLevel) -> Result<(), Error> {
+        let js_options = js_from_serde(&AccessOptions { access_level })?;
+        self.0
+            .unchecked_ref::<AccessLevelStorageArea>()
+            .set_access_level(object_from_js(&js_options)?)
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AccessOptions {
+    access_level: AccessLevel,
+}
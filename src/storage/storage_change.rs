@@ -0,0 +1,33 @@
+use super::prelude::*;
+use std::collections::HashMap;
+
+/// The changes of a storage area, indexed by the key of the changed item.
+pub type StorageChanges = HashMap<String, StorageChange>;
+
+/// <https://developer.chrome.com/docs/extensions/reference/storage/#type-StorageChange>
+///
+/// The values are untyped by default,
+/// use [`StorageChange::into_typed`] to deserialize them.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageChange<T = serde_json::Value> {
+    /// The old value of the item, if there was an old value.
+    pub old_value: Option<T>,
+
+    /// The new value of the item, if there is a new value.
+    pub new_value: Option<T>,
+}
+
+impl StorageChange {
+    /// Deserializes the old and the new value into `T`.
+    pub fn into_typed<T>(self) -> Result<StorageChange<T>, Error>
+    where
+        T: for<'a> Deserialize<'a>,
+    {
+        let from_value = |v| serde_json::from_value(v).map_err(Error::JsonDeserialization);
+        Ok(StorageChange {
+            old_value: self.old_value.map(from_value).transpose()?,
+            new_value: self.new_value.map(from_value).transpose()?,
+        })
+    }
+}
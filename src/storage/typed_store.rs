@@ -0,0 +1,160 @@
+use super::{prelude::*, StorageArea};
+use serde_json::Value;
+use std::{
+    collections::{BTreeMap, HashMap},
+    marker::PhantomData,
+};
+
+/// A value together with the version of the schema it was serialized with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Versioned<T> {
+    pub version: u32,
+    pub data: T,
+}
+
+type Migration = Box<dyn Fn(Value) -> Result<Value, serde_json::Error>>;
+
+/// The current version of a serialized type
+/// and the migrations to upgrade older serialized shapes to it.
+pub struct Schema<T> {
+    version: u32,
+    migrations: BTreeMap<u32, Migration>,
+    _type: PhantomData<fn() -> T>,
+}
+
+impl<T> Schema<T>
+where
+    T: Serialize + for<'a> Deserialize<'a>,
+{
+    /// Creates a schema without any migrations.
+    pub fn new(version: u32) -> Self {
+        Self {
+            version,
+            migrations: BTreeMap::new(),
+            _type: PhantomData,
+        }
+    }
+
+    /// Registers a migration that upgrades data of version `from_version`
+    /// to the shape of version `from_version + 1`.
+    pub fn migration<F>(mut self, from_version: u32, migrate: F) -> Self
+    where
+        F: Fn(Value) -> Result<Value, serde_json::Error> + 'static,
+    {
+        self.migrations.insert(from_version, Box::new(migrate));
+        self
+    }
+
+    /// The current version of the schema.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Serializes a value with the current version.
+    pub fn encode(&self, value: &T) -> Result<Versioned<Value>, Error> {
+        let data = serde_json::to_value(value).map_err(Error::JsonSerialization)?;
+        Ok(Versioned {
+            version: self.version,
+            data,
+        })
+    }
+
+    /// Deserializes a stored value, applying all migrations
+    /// from its version up to the current version.
+    pub fn decode(&self, stored: Versioned<Value>) -> Result<T, Error> {
+        let Versioned {
+            mut version,
+            mut data,
+        } = stored;
+        if version > self.version {
+            return Err(Error::UnsupportedSchemaVersion {
+                found: version,
+                supported: self.version,
+            });
+        }
+        while version < self.version {
+            let migrate = self
+                .migrations
+                .get(&version)
+                .ok_or(Error::MissingMigration(version))?;
+            data = migrate(data).map_err(Error::JsonDeserialization)?;
+            version += 1;
+        }
+        serde_json::from_value(data).map_err(Error::JsonDeserialization)
+    }
+
+    /// Deserializes a value as it was read from storage.
+    ///
+    /// Values written before the schema was adopted are not wrapped in
+    /// [`Versioned`], they are treated as data of version 0.
+    /// Only an object with exactly the fields `version` and `data`
+    /// is considered to be wrapped.
+    pub fn decode_value(&self, stored: Value) -> Result<T, Error> {
+        let is_versioned = stored.as_object().is_some_and(|object| {
+            object.len() == 2
+                && object.get("version").is_some_and(Value::is_u64)
+                && object.contains_key("data")
+        });
+        let stored = if is_versioned {
+            serde_json::from_value(stored).map_err(Error::JsonDeserialization)?
+        } else {
+            Versioned {
+                version: 0,
+                data: stored,
+            }
+        };
+        self.decode(stored)
+    }
+}
+
+/// A typed value stored under a namespaced key of a [`StorageArea`].
+///
+/// Values are stored along with the version of their [`Schema`],
+/// so values written by an older release of the extension
+/// are migrated when they are read.
+pub struct TypedStore<T> {
+    area: StorageArea,
+    key: String,
+    schema: Schema<T>,
+}
+
+impl<T> TypedStore<T>
+where
+    T: Serialize + for<'a> Deserialize<'a>,
+{
+    /// Creates a store for the key `"{namespace}/{name}"`.
+    pub fn new(area: StorageArea, namespace: &str, name: &str, schema: Schema<T>) -> Self {
+        Self {
+            area,
+            key: format!("{namespace}/{name}"),
+            schema,
+        }
+    }
+
+    /// The key the value is stored under.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Reads and migrates the stored value, if any.
+    ///
+    /// See [`Schema::decode_value`] for values that were stored without a version.
+    pub async fn get(&self) -> Result<Option<T>, Error> {
+        let mut items: HashMap<String, Value> = self.area.get(&self.key).await?;
+        items
+            .remove(&self.key)
+            .map(|stored| self.schema.decode_value(stored))
+            .transpose()
+    }
+
+    /// Stores the value with the current schema version.
+    pub async fn set(&self, value: &T) -> Result<(), Error> {
+        let stored = self.schema.encode(value)?;
+        self.area.set(&HashMap::from([(&self.key, stored)])).await
+    }
+
+    /// Removes the stored value.
+    pub async fn remove(&self) -> Result<(), Error> {
+        self.area.remove(&self.key).await
+    }
+}
@@ -0,0 +1,17 @@
+use super::prelude::*;
+
+/// <https://developer.chrome.com/docs/extensions/reference/tabs/#method-connect-connectInfo>
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectInfo<'a> {
+    /// Open a port to a specific document identified by `document_id`
+    /// instead of all frames in the tab.
+    pub document_id: Option<&'a str>,
+
+    /// Open a port to a specific frame identified by `frame_id`
+    /// instead of all frames in the tab.
+    pub frame_id: Option<u32>,
+
+    /// Is passed into `onConnect` for content scripts that are listening for the connection event.
+    pub name: Option<&'a str>,
+}
@@ -1,28 +1,39 @@
 //! Wrapper for the [`chrome.tabs` API](https://developer.chrome.com/docs/extensions/reference/tabs/).
 
 pub(crate) mod prelude {
-    pub(crate) use crate::util::{js_from_serde, object_from_js, serde_from_js_result};
-    pub use crate::{event_listener::EventListener, tabs::TabId, Error};
+    pub(crate) use crate::event_stream::define_event;
+    pub(crate) use crate::util::{
+        js_from_serde, object_from_js, serde_from_js, serde_from_js_result,
+    };
+    pub use crate::{tabs::TabId, windows::WindowId, Error};
     pub use serde::{Deserialize, Serialize};
-    pub use wasm_bindgen::closure::Closure;
     pub use web_extensions_sys as sys;
 
     pub fn tabs() -> sys::Tabs {
-        // Currently we assume a chrome browser and Manifest V3.
-        //
-        // Once MV3 is supported by FireFox, we need to check if we can use the same namespace,
-        // a shim or our own implementation.
-        sys::chrome().tabs()
+        crate::browser().api().tabs()
     }
 }
 
 use self::prelude::*;
+use crate::runtime::Port;
+use js_sys::Object;
+use wasm_bindgen::{prelude::*, JsCast};
+
+#[wasm_bindgen]
+extern "C" {
+    // `web_extensions_sys::Tabs::connect` is bound as an async function,
+    // but `connect` returns the port synchronously.
+    type ConnectingTabs;
+
+    #[wasm_bindgen(method)]
+    fn connect(this: &ConnectingTabs, tab_id: i32, connect_info: &Object) -> sys::Port;
+}
 
 /// The ID of the tab.
 ///
 /// Tab IDs are unique within a browser session.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-pub struct TabId(i32);
+pub struct TabId(pub(crate) i32);
 
 impl From<i32> for TabId {
     fn from(id: i32) -> Self {
@@ -41,16 +52,22 @@ mod on_replaced;
 mod on_updated;
 mod on_zoom_change;
 
+mod connect_info;
+mod move_properties;
 mod muted_info;
 mod query_details;
+mod reload_properties;
+mod send_message_options;
 mod status;
 mod tab;
+mod update_properties;
 mod window_type;
 
 pub use self::{
-    muted_info::*, on_activated::*, on_attached::*, on_created::*, on_detached::*,
-    on_highlighted::*, on_moved::*, on_removed::*, on_replaced::*, on_updated::*,
-    on_zoom_change::*, query_details::*, status::*, tab::*, window_type::*,
+    connect_info::*, move_properties::*, muted_info::*, on_activated::*, on_attached::*,
+    on_created::*, on_detached::*, on_highlighted::*, on_moved::*, on_removed::*, on_replaced::*,
+    on_updated::*, on_zoom_change::*, query_details::*, reload_properties::*,
+    send_message_options::*, status::*, tab::*, update_properties::*, window_type::*,
 };
 
 /// <https://developer.chrome.com/docs/extensions/reference/tabs/#method-get>
@@ -67,17 +84,49 @@ pub async fn query(details: &QueryDetails<'_>) -> Result<Vec<Tab>, Error> {
 }
 
 /// <https://developer.chrome.com/docs/extensions/reference/tabs/#method-sendMessage>
+///
+/// Any response of the receiver is ignored,
+/// use [`send_message_with_response`] to read it.
 pub async fn send_message<T>(tab_id: TabId, message: &T) -> Result<(), Error>
 where
     T: Serialize,
+{
+    let options = SendMessageOptions::default();
+    send_message_with_response::<_, serde::de::IgnoredAny>(tab_id, message, &options).await?;
+    Ok(())
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/tabs/#method-sendMessage>
+///
+/// Returns [`Error::NoReceiver`] if no listener in the tab
+/// (or the selected frame or document) received the message.
+pub async fn send_message_with_response<Req, Resp>(
+    tab_id: TabId,
+    message: &Req,
+    options: &SendMessageOptions<'_>,
+) -> Result<Resp, Error>
+where
+    Req: Serialize,
+    Resp: for<'a> Deserialize<'a>,
 {
     let js_message = js_from_serde(message)?;
-    let options = None;
-    tabs()
-        .send_message(tab_id.0, &js_message, options)
+    let js_options = js_from_serde(options)?;
+    let result = tabs()
+        .send_message(tab_id.0, &js_message, Some(object_from_js(&js_options)?))
         .await
-        .map(|_| ())?;
-    Ok(())
+        .map_err(Error::from_send_message)?;
+    serde_from_js(result)
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/tabs/#method-connect>
+///
+/// Opens a long-lived connection to the content scripts in the tab.
+pub fn connect(tab_id: TabId, info: &ConnectInfo<'_>) -> Result<Port, Error> {
+    let js_info = js_from_serde(info)?;
+    let port = tabs()
+        .unchecked_ref::<ConnectingTabs>()
+        .connect(tab_id.0, object_from_js(&js_info)?);
+    Ok(Port::new(port))
 }
 
 /// <https://developer.chrome.com/docs/extensions/reference/tabs/#method-create>
@@ -87,6 +136,45 @@ pub async fn create(props: CreateProperties<'_>) -> Result<Tab, Error> {
     serde_from_js_result(result)
 }
 
+/// <https://developer.chrome.com/docs/extensions/reference/tabs/#method-update>
+pub async fn update(tab_id: TabId, props: &UpdateProperties<'_>) -> Result<Tab, Error> {
+    let js_props = js_from_serde(props)?;
+    let result = tabs()
+        .update(Some(tab_id.0), object_from_js(&js_props)?)
+        .await;
+    serde_from_js_result(result)
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/tabs/#method-remove>
+pub async fn remove(tab_ids: &[TabId]) -> Result<(), Error> {
+    let js_tab_ids = js_from_serde(&tab_ids)?;
+    tabs().remove(&js_tab_ids).await?;
+    Ok(())
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/tabs/#method-reload>
+pub async fn reload(tab_id: TabId, props: ReloadProperties) -> Result<(), Error> {
+    let js_props = js_from_serde(&props)?;
+    tabs()
+        .reload(Some(tab_id.0), Some(object_from_js(&js_props)?))
+        .await?;
+    Ok(())
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/tabs/#method-duplicate>
+pub async fn duplicate(tab_id: TabId) -> Result<Tab, Error> {
+    let result = tabs().duplicate(tab_id.0).await;
+    serde_from_js_result(result)
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/tabs/#method-move>
+pub async fn move_tabs(tab_ids: &[TabId], props: MoveProperties) -> Result<Vec<Tab>, Error> {
+    let js_tab_ids = js_from_serde(&tab_ids)?;
+    let js_props = js_from_serde(&props)?;
+    let result = tabs().move_(&js_tab_ids, object_from_js(&js_props)?).await;
+    serde_from_js_result(result)
+}
+
 /// Information necessary to open a new tab.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CreateProperties<'a> {
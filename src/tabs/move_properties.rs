@@ -0,0 +1,14 @@
+use super::prelude::*;
+
+/// <https://developer.chrome.com/docs/extensions/reference/tabs/#method-move-moveProperties>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveProperties {
+    /// The position to move the tabs to.
+    ///
+    /// Use `-1` to place the tabs at the end of the window.
+    pub index: i32,
+
+    /// Defaults to the window the tab is currently in.
+    pub window_id: Option<WindowId>,
+}
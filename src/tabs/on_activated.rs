@@ -5,26 +5,11 @@ pub fn on_activated() -> OnActivated {
     OnActivated(tabs().on_activated())
 }
 
-/// <https://developer.chrome.com/docs/extensions/reference/tabs/#event-onActivated>
-pub struct OnActivated(sys::EventTarget);
-
-/// <https://developer.chrome.com/docs/extensions/reference/tabs/#method-onActivated-callback>
-pub struct OnActivatedEventListener<'a>(EventListener<'a, dyn FnMut(sys::TabActiveInfo)>);
-
-impl OnActivatedEventListener<'_> {
-    pub fn forget(self) {
-        self.0.forget()
-    }
-}
-
-impl OnActivated {
-    pub fn add_listener<L>(&self, mut listener: L) -> OnActivatedEventListener
-    where
-        L: FnMut(ActiveInfo) + 'static,
-    {
-        let listener =
-            Closure::new(move |info: sys::TabActiveInfo| listener(ActiveInfo::from(info)));
-        OnActivatedEventListener(EventListener::raw_new(&self.0, listener))
+define_event! {
+    /// <https://developer.chrome.com/docs/extensions/reference/tabs/#event-onActivated>
+    pub struct OnActivated, OnActivatedEventListener;
+    fn(info: sys::TabActiveInfo) -> (info: ActiveInfo) {
+        Some(ActiveInfo::from(info))
     }
 }
 
@@ -33,7 +18,7 @@ impl OnActivated {
 #[serde(rename_all = "camelCase")]
 pub struct ActiveInfo {
     pub tab_id: TabId,
-    pub window_id: i32,
+    pub window_id: WindowId,
 }
 
 impl From<sys::TabActiveInfo> for ActiveInfo {
@@ -41,7 +26,7 @@ impl From<sys::TabActiveInfo> for ActiveInfo {
         let tab_id = TabId::from(info.tab_id());
         ActiveInfo {
             tab_id,
-            window_id: info.window_id(),
+            window_id: WindowId::from(info.window_id()),
         }
     }
 }
@@ -5,33 +5,18 @@ pub fn on_detached() -> OnDetached {
     OnDetached(tabs().on_detached())
 }
 
-/// <https://developer.chrome.com/docs/extensions/reference/tabs/#event-onDetached>
-pub struct OnDetached(sys::EventTarget);
-
-pub struct OnDetachedEventListener<'a>(EventListener<'a, dyn FnMut(i32, sys::TabDetachInfo)>);
-
-impl OnDetachedEventListener<'_> {
-    pub fn forget(self) {
-        self.0.forget()
-    }
-}
-
-impl OnDetached {
-    pub fn add_listener<L>(&self, mut listener: L) -> OnDetachedEventListener
-    where
-        L: FnMut(i32, DetachInfo) + 'static,
-    {
-        let listener = Closure::new(move |tab_id: i32, info: sys::TabDetachInfo| {
-            listener(tab_id, DetachInfo::from(info))
-        });
-        OnDetachedEventListener(EventListener::raw_new(&self.0, listener))
+define_event! {
+    /// <https://developer.chrome.com/docs/extensions/reference/tabs/#event-onDetached>
+    pub struct OnDetached, OnDetachedEventListener;
+    fn(tab_id: i32, info: sys::TabDetachInfo) -> (tab_id: i32, info: DetachInfo) {
+        Some((tab_id, DetachInfo::from(info)))
     }
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DetachInfo {
-    pub old_window_id: i32,
+    pub old_window_id: WindowId,
     pub old_position: u32,
 }
 
@@ -39,7 +24,7 @@ impl From<sys::TabDetachInfo> for DetachInfo {
     fn from(info: sys::TabDetachInfo) -> Self {
         Self {
             old_position: info.old_position(),
-            old_window_id: info.old_window_id(),
+            old_window_id: WindowId::from(info.old_window_id()),
         }
     }
 }
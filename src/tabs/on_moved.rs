@@ -5,33 +5,18 @@ pub fn on_moved() -> OnMoved {
     OnMoved(tabs().on_moved())
 }
 
-/// <https://developer.chrome.com/docs/extensions/reference/tabs/#event-onMoved>
-pub struct OnMoved(sys::EventTarget);
-
-pub struct OnMovedEventListener<'a>(EventListener<'a, dyn FnMut(i32, sys::TabMoveInfo)>);
-
-impl OnMovedEventListener<'_> {
-    pub fn forget(self) {
-        self.0.forget()
-    }
-}
-
-impl OnMoved {
-    pub fn add_listener<L>(&self, mut listener: L) -> OnMovedEventListener
-    where
-        L: FnMut(TabId, MoveInfo) + 'static,
-    {
-        let listener = Closure::new(move |tab_id: i32, tab: sys::TabMoveInfo| {
-            listener(TabId::from(tab_id), MoveInfo::from(tab))
-        });
-        OnMovedEventListener(EventListener::raw_new(&self.0, listener))
+define_event! {
+    /// <https://developer.chrome.com/docs/extensions/reference/tabs/#event-onMoved>
+    pub struct OnMoved, OnMovedEventListener;
+    fn(tab_id: i32, info: sys::TabMoveInfo) -> (tab_id: TabId, info: MoveInfo) {
+        Some((TabId::from(tab_id), MoveInfo::from(info)))
     }
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MoveInfo {
-    pub window_id: i32,
+    pub window_id: WindowId,
     pub from_index: u32,
     pub to_index: u32,
 }
@@ -41,7 +26,7 @@ impl From<sys::TabMoveInfo> for MoveInfo {
         Self {
             from_index: info.from_index(),
             to_index: info.to_index(),
-            window_id: info.window_id(),
+            window_id: WindowId::from(info.window_id()),
         }
     }
 }
@@ -5,31 +5,13 @@ pub fn on_updated() -> OnUpdated {
     OnUpdated(tabs().on_updated())
 }
 
-/// <https://developer.chrome.com/docs/extensions/reference/tabs/#event-onUpdated>
-pub struct OnUpdated(sys::EventTarget);
-
-/// <https://developer.chrome.com/docs/extensions/reference/tabs/#method-onUpdated-callback>
-pub struct OnUpdatedEventListener<'a>(
-    EventListener<'a, dyn FnMut(i32, sys::TabChangeInfo, sys::Tab)>,
-);
-
-impl OnUpdatedEventListener<'_> {
-    pub fn forget(self) {
-        self.0.forget()
-    }
-}
-
-impl OnUpdated {
-    pub fn add_listener<L>(&self, mut listener: L) -> OnUpdatedEventListener
-    where
-        L: FnMut(TabId, ChangeInfo, Tab) + 'static,
+define_event! {
+    /// <https://developer.chrome.com/docs/extensions/reference/tabs/#event-onUpdated>
+    pub struct OnUpdated, OnUpdatedEventListener;
+    fn(tab_id: i32, info: sys::TabChangeInfo, tab: sys::Tab)
+        -> (tab_id: TabId, info: ChangeInfo, tab: Tab)
     {
-        let listener = Closure::new(
-            move |tab_id: i32, info: sys::TabChangeInfo, tab: sys::Tab| {
-                listener(TabId::from(tab_id), ChangeInfo::from(info), Tab::from(tab))
-            },
-        );
-        OnUpdatedEventListener(EventListener::raw_new(&self.0, listener))
+        Some((TabId::from(tab_id), ChangeInfo::from(info), Tab::from(tab)))
     }
 }
 
@@ -70,7 +52,7 @@ pub struct ChangeInfo {
 
 impl From<sys::TabChangeInfo> for ChangeInfo {
     fn from(info: sys::TabChangeInfo) -> Self {
-        let status = info.status().map(|s| Status::try_from(s).expect("status"));
+        let status = info.status().and_then(|s| Status::try_from(s).ok());
         let muted_info = info.muted_info().map(MutedInfo::from);
         Self {
             status,
@@ -5,25 +5,11 @@ pub fn on_zoom_change() -> OnZoomChange {
     OnZoomChange(tabs().on_zoom_change())
 }
 
-/// <https://developer.chrome.com/docs/extensions/reference/tabs/#event-onZoomChange>
-pub struct OnZoomChange(sys::EventTarget);
-
-pub struct OnZoomChangeEventListener<'a>(EventListener<'a, dyn FnMut(sys::TabZoomChangeInfo)>);
-
-impl OnZoomChangeEventListener<'_> {
-    pub fn forget(self) {
-        self.0.forget()
-    }
-}
-
-impl OnZoomChange {
-    pub fn add_listener<L>(&self, mut listener: L) -> OnZoomChangeEventListener
-    where
-        L: FnMut(ZoomChangeInfo) + 'static,
-    {
-        let listener =
-            Closure::new(move |info: sys::TabZoomChangeInfo| listener(ZoomChangeInfo::from(info)));
-        OnZoomChangeEventListener(EventListener::raw_new(&self.0, listener))
+define_event! {
+    /// <https://developer.chrome.com/docs/extensions/reference/tabs/#event-onZoomChange>
+    pub struct OnZoomChange, OnZoomChangeEventListener;
+    fn(info: sys::TabZoomChangeInfo) -> (info: ZoomChangeInfo) {
+        Some(ZoomChangeInfo::from(info))
     }
 }
 
@@ -1,7 +1,7 @@
 use super::{prelude::*, Status, WindowType};
 
 /// <https://developer.chrome.com/docs/extensions/reference/tabs/#type-query-queryInfo>
-#[derive(Debug, Serialize)]
+#[derive(Debug, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryDetails<'a> {
     pub active: Option<bool>,
@@ -19,6 +19,7 @@ pub struct QueryDetails<'a> {
     pub status: Option<Status>,
     pub title: Option<&'a str>,
     pub url: Option<&'a str>,
-    pub window_id: i32,
+    /// The window to query, e.g. [`WindowId::CURRENT`], or all windows if `None`.
+    pub window_id: Option<WindowId>,
     pub window_type: Option<WindowType>,
 }
@@ -0,0 +1,11 @@
+use super::prelude::*;
+
+/// <https://developer.chrome.com/docs/extensions/reference/tabs/#method-reload-reloadProperties>
+#[derive(Debug, Default, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReloadProperties {
+    /// Whether to bypass local caching.
+    ///
+    /// Defaults to `false`.
+    pub bypass_cache: Option<bool>,
+}
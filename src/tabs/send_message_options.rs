@@ -0,0 +1,14 @@
+use super::prelude::*;
+
+/// <https://developer.chrome.com/docs/extensions/reference/tabs/#method-sendMessage-options>
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendMessageOptions<'a> {
+    /// Send a message to a specific document identified by `document_id`
+    /// instead of all frames in the tab.
+    pub document_id: Option<&'a str>,
+
+    /// Send a message to a specific frame identified by `frame_id`
+    /// instead of all frames in the tab.
+    pub frame_id: Option<u32>,
+}
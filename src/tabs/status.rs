@@ -2,7 +2,7 @@ use super::prelude::*;
 use thiserror::Error;
 
 /// <https://developer.chrome.com/docs/extensions/reference/tabs/#type-TabStatus>
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Status {
     #[serde(rename(serialize = "unloaded", deserialize = "unloaded"))]
     Unloaded,
@@ -1,14 +1,21 @@
 use super::{prelude::*, Status};
+use wasm_bindgen::JsValue;
 
 /// <https://developer.chrome.com/docs/extensions/reference/tabs/#type-Tab>
-#[derive(Debug, PartialEq, Eq, Deserialize)]
+///
+/// Firefox omits the properties it does not support,
+/// they fall back to the values Chrome reports for an ungrouped tab.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Tab {
     pub active: bool,
     pub audible: Option<bool>,
+    #[serde(default = "auto_discardable_default")]
     pub auto_discardable: bool,
+    #[serde(default)]
     pub discarded: bool,
     pub fav_icon_url: Option<String>,
+    #[serde(default = "group_id_default")]
     pub group_id: i32,
     pub height: Option<u32>,
     pub highlighted: bool,
@@ -24,24 +31,44 @@ pub struct Tab {
     pub title: Option<String>,
     pub url: Option<String>,
     pub width: Option<u32>,
-    pub window_id: i32,
+    pub window_id: WindowId,
+}
+
+fn auto_discardable_default() -> bool {
+    true
+}
+
+/// `tabGroups.TAB_GROUP_ID_NONE`
+fn group_id_default() -> i32 {
+    -1
 }
 
 impl From<sys::Tab> for Tab {
     fn from(info: sys::Tab) -> Self {
-        let status = info.status().map(|s| Status::try_from(s).expect("status"));
+        let status = info.status().and_then(|s| Status::try_from(s).ok());
         let id = info.id().map(TabId::from);
         let opener_tab_id = info.opener_tab_id().map(TabId::from);
+        // Without the `firefox` feature, `web_extensions_sys` reads missing
+        // properties as `false` and `0`, so they are looked up here instead.
+        let auto_discardable = property(&info, "autoDiscardable")
+            .and_then(|value| value.as_bool())
+            .unwrap_or_else(auto_discardable_default);
+        let discarded = property(&info, "discarded")
+            .and_then(|value| value.as_bool())
+            .unwrap_or_default();
+        let group_id = property(&info, "groupId")
+            .and_then(|value| value.as_f64())
+            .map_or_else(group_id_default, |group_id| group_id as i32);
         Self {
             id,
             opener_tab_id,
             status,
             active: info.active(),
             audible: info.audible(),
-            auto_discardable: info.auto_discardable(),
-            discarded: info.discarded(),
+            auto_discardable,
+            discarded,
             fav_icon_url: info.fav_icon_url(),
-            group_id: info.group_id(),
+            group_id,
             height: info.height(),
             highlighted: info.highlighted(),
             incognito: info.incognito(),
@@ -52,7 +79,13 @@ impl From<sys::Tab> for Tab {
             title: info.title(),
             url: info.url(),
             width: info.width(),
-            window_id: info.window_id(),
+            window_id: WindowId::from(info.window_id()),
         }
     }
 }
+
+fn property(tab: &sys::Tab, name: &str) -> Option<JsValue> {
+    js_sys::Reflect::get(tab, &JsValue::from_str(name))
+        .ok()
+        .filter(|value| !value.is_undefined())
+}
@@ -0,0 +1,29 @@
+use super::prelude::*;
+
+/// <https://developer.chrome.com/docs/extensions/reference/tabs/#method-update-updateProperties>
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateProperties<'a> {
+    /// Whether the tab should be active.
+    pub active: Option<bool>,
+
+    /// Whether the tab should be discarded automatically by the browser when resources are low.
+    pub auto_discardable: Option<bool>,
+
+    /// Adds or removes the tab from the current selection.
+    pub highlighted: Option<bool>,
+
+    /// Whether the tab should be muted.
+    pub muted: Option<bool>,
+
+    /// The ID of the tab that opened this tab.
+    ///
+    /// If specified, the opener tab must be in the same window as this tab.
+    pub opener_tab_id: Option<TabId>,
+
+    /// Whether the tab should be pinned.
+    pub pinned: Option<bool>,
+
+    /// A URL to navigate the tab to.
+    pub url: Option<&'a str>,
+}
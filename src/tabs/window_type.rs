@@ -1,14 +1,35 @@
 use super::prelude::*;
+use thiserror::Error;
 
 /// <https://developer.chrome.com/docs/extensions/reference/tabs/#type-WindowType>
-#[derive(Debug, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum WindowType {
-    #[serde(rename(serialize = "normal"))]
+    #[serde(rename(serialize = "normal", deserialize = "normal"))]
     Normal,
-    #[serde(rename(serialize = "popup"))]
+    #[serde(rename(serialize = "popup", deserialize = "popup"))]
     Popup,
-    #[serde(rename(serialize = "panel"))]
+    #[serde(rename(serialize = "panel", deserialize = "panel"))]
     Panel,
-    #[serde(rename(serialize = "devtools"))]
+    #[serde(rename(serialize = "app", deserialize = "app"))]
+    App,
+    #[serde(rename(serialize = "devtools", deserialize = "devtools"))]
     Devtools,
 }
+
+#[derive(Debug, Error)]
+#[error("Invalid window type ('{0}'), expected 'normal', 'popup', 'panel', 'app' or 'devtools'")]
+pub struct InvalidWindowTypeError(String);
+
+impl TryFrom<String> for WindowType {
+    type Error = InvalidWindowTypeError;
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        match &*s {
+            "normal" => Ok(WindowType::Normal),
+            "popup" => Ok(WindowType::Popup),
+            "panel" => Ok(WindowType::Panel),
+            "app" => Ok(WindowType::App),
+            "devtools" => Ok(WindowType::Devtools),
+            _ => Err(InvalidWindowTypeError(s)),
+        }
+    }
+}
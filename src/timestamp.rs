@@ -0,0 +1,349 @@
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use thiserror::Error;
+
+const MS_PER_SECOND: i64 = 1000;
+const MS_PER_DAY: i64 = 24 * 60 * 60 * MS_PER_SECOND;
+
+/// The latest year a JavaScript `Date` can represent.
+const MAX_YEAR: i64 = 275_760;
+
+/// A point in time, represented in milliseconds since the Unix epoch.
+///
+/// The browser APIs represent times either as milliseconds, sometimes with
+/// a fractional part, or as ISO 8601 strings.
+/// A `Timestamp` deserializes from both and serializes as milliseconds.
+/// Fields that the browser expects as ISO 8601 string are serialized as such.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Timestamp(f64);
+
+impl Timestamp {
+    /// The Unix epoch, i.e. `1970-01-01T00:00:00Z`.
+    pub const UNIX_EPOCH: Self = Self(0.0);
+
+    pub const fn from_millis(millis: f64) -> Self {
+        Self(millis)
+    }
+
+    /// Milliseconds since the Unix epoch, negative for earlier times.
+    pub const fn as_millis(&self) -> f64 {
+        self.0
+    }
+
+    /// Nanoseconds since the Unix epoch, rounded to avoid floating point artifacts.
+    ///
+    /// Returns `None` if the timestamp is not finite or too large.
+    fn as_nanos(&self) -> Option<i128> {
+        if !self.0.is_finite() {
+            return None;
+        }
+        let millis = self.0.floor();
+        let nanos = ((self.0 - millis) * 1_000_000.0).round();
+        // Casting saturates, so a huge value overflows the multiplication.
+        (millis as i128)
+            .checked_mul(1_000_000)?
+            .checked_add(nanos as i128)
+    }
+
+    fn out_of_range(&self) -> TimestampOutOfRangeError {
+        TimestampOutOfRangeError(self.0)
+    }
+
+    /// Formats the timestamp in ISO 8601 format with millisecond precision,
+    /// e.g. `2022-11-09T10:15:30.000Z`.
+    pub fn to_iso8601(&self) -> String {
+        let millis = self.0.floor() as i64;
+        let days = millis.div_euclid(MS_PER_DAY);
+        let millis_of_day = millis.rem_euclid(MS_PER_DAY);
+        let (year, month, day) = civil_from_days(days);
+        let seconds_of_day = millis_of_day / MS_PER_SECOND;
+        format!(
+            "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+            seconds_of_day / 3600,
+            seconds_of_day / 60 % 60,
+            seconds_of_day % 60,
+            millis_of_day % MS_PER_SECOND,
+        )
+    }
+
+    /// Parses an ISO 8601 date and time, e.g. `2022-11-09T10:15:30.000Z`
+    /// or `2022-11-09T11:15:30+01:00`.
+    ///
+    /// Times without offset are interpreted as UTC.
+    pub fn parse_iso8601(s: &str) -> Result<Self, InvalidTimestampError> {
+        parse_iso8601(s).ok_or_else(|| InvalidTimestampError(s.to_owned()))
+    }
+}
+
+#[derive(Debug, Error)]
+#[error(
+    "Invalid timestamp ('{0}'), expected milliseconds since the epoch or an ISO 8601 date and time"
+)]
+pub struct InvalidTimestampError(String);
+
+/// The error of converting a [`Timestamp`] into a type that cannot represent it.
+#[derive(Debug, Error)]
+#[error("Timestamp ({0} ms since the epoch) is out of range")]
+pub struct TimestampOutOfRangeError(f64);
+
+impl PartialEq for Timestamp {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Timestamp {}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Timestamp {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Hash for Timestamp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state)
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_iso8601())
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = InvalidTimestampError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_iso8601(s)
+    }
+}
+
+impl From<SystemTime> for Timestamp {
+    fn from(time: SystemTime) -> Self {
+        let millis = |duration: Duration| {
+            duration.as_secs() as f64 * 1000.0 + f64::from(duration.subsec_nanos()) / 1_000_000.0
+        };
+        match time.duration_since(UNIX_EPOCH) {
+            Ok(after) => Self(millis(after)),
+            Err(before) => Self(-millis(before.duration())),
+        }
+    }
+}
+
+impl TryFrom<Timestamp> for SystemTime {
+    type Error = TimestampOutOfRangeError;
+
+    fn try_from(timestamp: Timestamp) -> Result<Self, Self::Error> {
+        let nanos = timestamp.as_nanos().ok_or(timestamp.out_of_range())?;
+        let abs_nanos = nanos.unsigned_abs();
+        let seconds =
+            u64::try_from(abs_nanos / 1_000_000_000).map_err(|_| timestamp.out_of_range())?;
+        let duration = Duration::new(seconds, (abs_nanos % 1_000_000_000) as u32);
+        if nanos < 0 {
+            UNIX_EPOCH.checked_sub(duration)
+        } else {
+            UNIX_EPOCH.checked_add(duration)
+        }
+        .ok_or(timestamp.out_of_range())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<Timestamp> for chrono::DateTime<chrono::Utc> {
+    type Error = TimestampOutOfRangeError;
+
+    fn try_from(timestamp: Timestamp) -> Result<Self, Self::Error> {
+        let nanos = timestamp.as_nanos().ok_or(timestamp.out_of_range())?;
+        let seconds =
+            i64::try_from(nanos.div_euclid(1_000_000_000)).map_err(|_| timestamp.out_of_range())?;
+        let subsec_nanos = nanos.rem_euclid(1_000_000_000) as u32;
+        chrono::DateTime::from_timestamp(seconds, subsec_nanos).ok_or(timestamp.out_of_range())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for Timestamp {
+    fn from(time: chrono::DateTime<Tz>) -> Self {
+        Self(
+            time.timestamp_millis() as f64
+                + f64::from(time.timestamp_subsec_nanos() % 1_000_000) / 1_000_000.0,
+        )
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<Timestamp> for time::OffsetDateTime {
+    type Error = TimestampOutOfRangeError;
+
+    fn try_from(timestamp: Timestamp) -> Result<Self, Self::Error> {
+        let nanos = timestamp.as_nanos().ok_or(timestamp.out_of_range())?;
+        time::OffsetDateTime::from_unix_timestamp_nanos(nanos).map_err(|_| timestamp.out_of_range())
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for Timestamp {
+    fn from(time: time::OffsetDateTime) -> Self {
+        let nanos = time.unix_timestamp_nanos();
+        Self(nanos.div_euclid(1_000_000) as f64 + nanos.rem_euclid(1_000_000) as f64 / 1_000_000.0)
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.0.fract() == 0.0 && self.0.abs() < i64::MAX as f64 {
+            serializer.serialize_i64(self.0 as i64)
+        } else {
+            serializer.serialize_f64(self.0)
+        }
+    }
+}
+
+/// Serializes an optional timestamp as ISO 8601 string, e.g. for queries that expect strings.
+pub(crate) fn serialize_iso8601<S: Serializer>(
+    timestamp: &Option<Timestamp>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match timestamp {
+        Some(timestamp) => serializer.serialize_str(&timestamp.to_iso8601()),
+        None => serializer.serialize_none(),
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(TimestampVisitor)
+    }
+}
+
+struct TimestampVisitor;
+
+impl de::Visitor<'_> for TimestampVisitor {
+    type Value = Timestamp;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("milliseconds since the epoch or an ISO 8601 date and time")
+    }
+
+    fn visit_i64<E: de::Error>(self, millis: i64) -> Result<Timestamp, E> {
+        Ok(Timestamp(millis as f64))
+    }
+
+    fn visit_u64<E: de::Error>(self, millis: u64) -> Result<Timestamp, E> {
+        Ok(Timestamp(millis as f64))
+    }
+
+    fn visit_f64<E: de::Error>(self, millis: f64) -> Result<Timestamp, E> {
+        Ok(Timestamp(millis))
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Timestamp, E> {
+        Timestamp::parse_iso8601(s).map_err(E::custom)
+    }
+}
+
+fn parse_iso8601(s: &str) -> Option<Timestamp> {
+    fn number(s: &str) -> Option<i64> {
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        s.parse().ok()
+    }
+    let (date, time) = match s.split_once(['T', 't', ' ']) {
+        Some((date, time)) => (date, Some(time)),
+        None => (s, None),
+    };
+    let mut date_parts = date.splitn(3, '-');
+    let year = number(date_parts.next()?)?;
+    let month = number(date_parts.next()?)?;
+    let day = number(date_parts.next()?)?;
+    if year > MAX_YEAR || !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month)
+    {
+        return None;
+    }
+    let mut millis = days_from_civil(year, month, day) * MS_PER_DAY;
+    if let Some(time) = time {
+        let (time, offset_minutes) = if let Some(time) = time.strip_suffix(['Z', 'z']) {
+            (time, 0)
+        } else if let Some(sign_position) = time.rfind(['+', '-']) {
+            let (time, offset) = time.split_at(sign_position);
+            let sign = if offset.starts_with('-') { -1 } else { 1 };
+            let offset = &offset[1..];
+            let (hours, minutes) = match offset.split_once(':') {
+                Some(hours_and_minutes) => hours_and_minutes,
+                None if offset.len() == 4 => offset.split_at(2),
+                None => (offset, "0"),
+            };
+            let (hours, minutes) = (number(hours)?, number(minutes)?);
+            if hours > 23 || minutes > 59 {
+                return None;
+            }
+            (time, sign * (hours * 60 + minutes))
+        } else {
+            (time, 0)
+        };
+        let (time, fraction) = time.split_once(['.', ',']).unwrap_or((time, ""));
+        let mut time_parts = time.splitn(3, ':');
+        let hours = number(time_parts.next()?)?;
+        let minutes = number(time_parts.next()?)?;
+        let seconds = time_parts.next().map_or(Some(0), number)?;
+        if hours > 23 || minutes > 59 || seconds > 60 {
+            return None;
+        }
+        let fraction_millis = if fraction.is_empty() {
+            0.0
+        } else {
+            number(fraction)?;
+            format!("0.{fraction}").parse::<f64>().ok()? * 1000.0
+        };
+        millis += ((hours * 60 + minutes - offset_minutes) * 60 + seconds) * MS_PER_SECOND;
+        return Some(Timestamp(millis as f64 + fraction_millis));
+    }
+    Some(Timestamp(millis as f64))
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+// <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+pub(crate) fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
@@ -1,9 +1,16 @@
 use crate::error::Error;
 use gloo_utils::format::JsValueSerdeExt;
 use js_sys::Object;
-use wasm_bindgen::prelude::*;
+use wasm_bindgen::{prelude::*, JsCast};
+use web_extensions_sys as sys;
 
-pub(crate) fn js_from_serde<T: serde::Serialize>(v: &T) -> Result<JsValue, Error> {
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = console, js_name = error)]
+    fn console_error(message: &str);
+}
+
+pub(crate) fn js_from_serde<T: serde::Serialize + ?Sized>(v: &T) -> Result<JsValue, Error> {
     JsValue::from_serde(v).map_err(Error::JsonSerialization)
 }
 
@@ -24,3 +31,23 @@ where
 {
     v.into_serde().map_err(Error::JsonDeserialization)
 }
+
+/// Looks up an event that not every browser provides,
+/// e.g. Firefox has no `windows.onBoundsChanged`.
+pub(crate) fn optional_event(
+    api: &JsValue,
+    namespace: &'static str,
+    event: &'static str,
+) -> Result<sys::EventTarget, Error> {
+    js_sys::Reflect::get(api, &JsValue::from_str(event))
+        .ok()
+        .filter(JsValue::is_object)
+        .map(JsCast::unchecked_into)
+        .ok_or(Error::UnsupportedEvent { namespace, event })
+}
+
+/// Reports an error that cannot be returned to the caller,
+/// e.g. because it occurred inside of a listener that the browser calls.
+pub(crate) fn log_error(context: &str, error: &Error) {
+    console_error(&format!("web-extensions: {context}: {error}"));
+}
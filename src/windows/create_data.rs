@@ -0,0 +1,51 @@
+use super::{prelude::*, WindowState};
+
+/// <https://developer.chrome.com/docs/extensions/reference/windows/#method-create-createData>
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateData<'a> {
+    /// If `true`, opens an active window.
+    pub focused: Option<bool>,
+
+    /// The height in pixels of the new window, including the frame.
+    pub height: Option<u32>,
+
+    /// Whether the new window should be an incognito window.
+    pub incognito: Option<bool>,
+
+    /// The number of pixels to position the new window from the left edge of the screen.
+    pub left: Option<i32>,
+
+    /// If `true`, the newly-created window's `window.opener` is set to the caller.
+    pub set_self_as_opener: Option<bool>,
+
+    /// The initial state of the window.
+    pub state: Option<WindowState>,
+
+    /// The ID of the tab to add to the new window.
+    pub tab_id: Option<TabId>,
+
+    /// The number of pixels to position the new window from the top edge of the screen.
+    pub top: Option<i32>,
+
+    /// Specifies what type of browser window to create.
+    #[serde(rename = "type")]
+    pub type_: Option<CreateType>,
+
+    /// The URLs to open as tabs in the window.
+    pub url: Option<Vec<&'a str>>,
+
+    /// The width in pixels of the new window, including the frame.
+    pub width: Option<u32>,
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/windows/#type-CreateType>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum CreateType {
+    #[serde(rename(serialize = "normal"))]
+    Normal,
+    #[serde(rename(serialize = "popup"))]
+    Popup,
+    #[serde(rename(serialize = "panel"))]
+    Panel,
+}
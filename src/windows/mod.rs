@@ -0,0 +1,105 @@
+//! Wrapper for the [`chrome.windows` API](https://developer.chrome.com/docs/extensions/reference/windows/).
+
+pub(crate) mod prelude {
+    pub(crate) use crate::event_stream::define_event;
+    pub(crate) use crate::util::{js_from_serde, object_from_js, serde_from_js_result};
+    pub use crate::{tabs::TabId, windows::WindowId, Error};
+    pub use serde::{Deserialize, Serialize};
+    pub use web_extensions_sys as sys;
+
+    pub fn windows() -> sys::Windows {
+        crate::browser().api().windows()
+    }
+}
+
+use self::prelude::*;
+
+/// The ID of the window.
+///
+/// Window IDs are unique within a browser session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct WindowId(pub(crate) i32);
+
+impl WindowId {
+    /// Represents the absence of a browser window.
+    pub const NONE: Self = Self(-1);
+
+    /// Represents the current window.
+    pub const CURRENT: Self = Self(-2);
+}
+
+impl From<i32> for WindowId {
+    fn from(id: i32) -> Self {
+        Self(id)
+    }
+}
+
+mod create_data;
+mod on_bounds_changed;
+mod on_created;
+mod on_focus_changed;
+mod on_removed;
+mod query_options;
+mod update_info;
+mod window;
+mod window_state;
+
+pub use self::{
+    create_data::*, on_bounds_changed::*, on_created::*, on_focus_changed::*, on_removed::*,
+    query_options::*, update_info::*, window::*, window_state::*,
+};
+pub use crate::tabs::WindowType;
+
+/// <https://developer.chrome.com/docs/extensions/reference/windows/#method-get>
+pub async fn get(window_id: WindowId, options: &QueryOptions<'_>) -> Result<Window, Error> {
+    let js_options = js_from_serde(options)?;
+    let result = windows()
+        .get(window_id.0, object_from_js(&js_options)?)
+        .await;
+    serde_from_js_result(result)
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/windows/#method-getAll>
+pub async fn get_all(options: &QueryOptions<'_>) -> Result<Vec<Window>, Error> {
+    let js_options = js_from_serde(options)?;
+    let result = windows().get_all(object_from_js(&js_options)?).await;
+    serde_from_js_result(result)
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/windows/#method-getCurrent>
+pub async fn get_current(options: &QueryOptions<'_>) -> Result<Window, Error> {
+    let js_options = js_from_serde(options)?;
+    let result = windows().get_current(object_from_js(&js_options)?).await;
+    serde_from_js_result(result)
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/windows/#method-getLastFocused>
+pub async fn get_last_focused(options: &QueryOptions<'_>) -> Result<Window, Error> {
+    let js_options = js_from_serde(options)?;
+    let result = windows()
+        .get_last_focused(object_from_js(&js_options)?)
+        .await;
+    serde_from_js_result(result)
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/windows/#method-create>
+pub async fn create(data: &CreateData<'_>) -> Result<Window, Error> {
+    let js_data = js_from_serde(data)?;
+    let result = windows().create(object_from_js(&js_data)?).await;
+    serde_from_js_result(result)
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/windows/#method-update>
+pub async fn update(window_id: WindowId, info: &UpdateInfo) -> Result<Window, Error> {
+    let js_info = js_from_serde(info)?;
+    let result = windows()
+        .update(window_id.0, object_from_js(&js_info)?)
+        .await;
+    serde_from_js_result(result)
+}
+
+/// <https://developer.chrome.com/docs/extensions/reference/windows/#method-remove>
+pub async fn remove(window_id: WindowId) -> Result<(), Error> {
+    windows().remove(window_id.0).await?;
+    Ok(())
+}
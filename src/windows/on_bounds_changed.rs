@@ -0,0 +1,18 @@
+use super::{prelude::*, Window};
+use crate::util::optional_event;
+
+/// <https://developer.chrome.com/docs/extensions/reference/windows/#event-onBoundsChanged>
+///
+/// Fails in Firefox, which does not support this event.
+pub fn on_bounds_changed() -> Result<OnBoundsChanged, Error> {
+    // `web_extensions_sys` does not expose this event (yet).
+    optional_event(&windows(), "windows", "onBoundsChanged").map(OnBoundsChanged)
+}
+
+define_event! {
+    /// <https://developer.chrome.com/docs/extensions/reference/windows/#event-onBoundsChanged>
+    pub struct OnBoundsChanged, OnBoundsChangedEventListener;
+    fn(window: sys::Window) -> (window: Window) {
+        Window::try_from(window).ok()
+    }
+}
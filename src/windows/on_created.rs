@@ -0,0 +1,14 @@
+use super::{prelude::*, Window};
+
+/// <https://developer.chrome.com/docs/extensions/reference/windows/#event-onCreated>
+pub fn on_created() -> OnCreated {
+    OnCreated(windows().on_created())
+}
+
+define_event! {
+    /// <https://developer.chrome.com/docs/extensions/reference/windows/#event-onCreated>
+    pub struct OnCreated, OnCreatedEventListener;
+    fn(window: sys::Window) -> (window: Window) {
+        Window::try_from(window).ok()
+    }
+}
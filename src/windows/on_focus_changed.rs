@@ -0,0 +1,16 @@
+use super::prelude::*;
+
+/// <https://developer.chrome.com/docs/extensions/reference/windows/#event-onFocusChanged>
+pub fn on_focus_changed() -> OnFocusChanged {
+    OnFocusChanged(windows().on_focus_changed())
+}
+
+define_event! {
+    /// <https://developer.chrome.com/docs/extensions/reference/windows/#event-onFocusChanged>
+    ///
+    /// The window ID is [`WindowId::NONE`] if all browser windows have lost focus.
+    pub struct OnFocusChanged, OnFocusChangedEventListener;
+    fn(window_id: i32) -> (window_id: WindowId) {
+        Some(WindowId::from(window_id))
+    }
+}
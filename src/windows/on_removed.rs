@@ -0,0 +1,14 @@
+use super::prelude::*;
+
+/// <https://developer.chrome.com/docs/extensions/reference/windows/#event-onRemoved>
+pub fn on_removed() -> OnRemoved {
+    OnRemoved(windows().on_removed())
+}
+
+define_event! {
+    /// <https://developer.chrome.com/docs/extensions/reference/windows/#event-onRemoved>
+    pub struct OnRemoved, OnRemovedEventListener;
+    fn(window_id: i32) -> (window_id: WindowId) {
+        Some(WindowId::from(window_id))
+    }
+}
@@ -0,0 +1,15 @@
+use super::{prelude::*, WindowType};
+
+/// <https://developer.chrome.com/docs/extensions/reference/windows/#type-QueryOptions>
+#[derive(Debug, Default, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryOptions<'a> {
+    /// If `true`, the [`Window`](super::Window) will have a `tabs` property
+    /// that contains a list of its tabs.
+    pub populate: Option<bool>,
+
+    /// Filters the windows based on their type.
+    ///
+    /// If unset, the default filter is `[Normal, Popup]`.
+    pub window_types: Option<&'a [WindowType]>,
+}
@@ -0,0 +1,28 @@
+use super::{prelude::*, WindowState};
+
+/// <https://developer.chrome.com/docs/extensions/reference/windows/#method-update-updateInfo>
+#[derive(Debug, Default, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateInfo {
+    /// If `true`, causes the window to be displayed in a manner that draws the user's attention
+    /// to the window, without changing the focused window.
+    pub draw_attention: Option<bool>,
+
+    /// If `true`, brings the window to the front.
+    pub focused: Option<bool>,
+
+    /// The height to resize the window to in pixels.
+    pub height: Option<u32>,
+
+    /// The offset from the left edge of the screen to move the window to in pixels.
+    pub left: Option<i32>,
+
+    /// The new state of the window.
+    pub state: Option<WindowState>,
+
+    /// The offset from the top edge of the screen to move the window to in pixels.
+    pub top: Option<i32>,
+
+    /// The width to resize the window to in pixels.
+    pub width: Option<u32>,
+}
@@ -0,0 +1,56 @@
+use super::{prelude::*, WindowState, WindowType};
+use crate::tabs::Tab;
+use wasm_bindgen::JsCast;
+
+/// <https://developer.chrome.com/docs/extensions/reference/windows/#type-Window>
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Window {
+    pub always_on_top: bool,
+    pub focused: bool,
+    pub height: Option<u32>,
+    pub id: Option<WindowId>,
+    pub incognito: bool,
+    pub left: Option<i32>,
+    pub session_id: Option<String>,
+    pub state: Option<WindowState>,
+    /// The tabs of the window.
+    ///
+    /// Only present if the window was requested with `populate` set.
+    pub tabs: Option<Vec<Tab>>,
+    pub title: Option<String>,
+    pub top: Option<i32>,
+    #[serde(rename = "type")]
+    pub type_: Option<WindowType>,
+    pub width: Option<u32>,
+}
+
+impl TryFrom<sys::Window> for Window {
+    type Error = Error;
+
+    fn try_from(info: sys::Window) -> Result<Self, Self::Error> {
+        let id = info.id().map(WindowId::from);
+        let state = info.state().map(WindowState::try_from).transpose()?;
+        let type_ = info.type_().map(WindowType::try_from).transpose()?;
+        let tabs = info.tabs().map(|tabs| {
+            tabs.iter()
+                .map(|tab| Tab::from(tab.unchecked_into::<sys::Tab>()))
+                .collect()
+        });
+        Ok(Self {
+            id,
+            state,
+            type_,
+            tabs,
+            always_on_top: info.always_on_top(),
+            focused: info.focused(),
+            height: info.height(),
+            incognito: info.incognito(),
+            left: info.left(),
+            session_id: info.session_id(),
+            title: info.title(),
+            top: info.top(),
+            width: info.width(),
+        })
+    }
+}
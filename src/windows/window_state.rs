@@ -0,0 +1,39 @@
+use super::prelude::*;
+use thiserror::Error;
+
+/// <https://developer.chrome.com/docs/extensions/reference/windows/#type-WindowState>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WindowState {
+    #[serde(rename(serialize = "normal", deserialize = "normal"))]
+    Normal,
+    #[serde(rename(serialize = "minimized", deserialize = "minimized"))]
+    Minimized,
+    #[serde(rename(serialize = "maximized", deserialize = "maximized"))]
+    Maximized,
+    #[serde(rename(serialize = "fullscreen", deserialize = "fullscreen"))]
+    Fullscreen,
+    #[serde(rename(serialize = "locked-fullscreen", deserialize = "locked-fullscreen"))]
+    LockedFullscreen,
+    /// Only supported by Firefox.
+    #[serde(rename(serialize = "docked", deserialize = "docked"))]
+    Docked,
+}
+
+#[derive(Debug, Error)]
+#[error("Invalid window state ('{0}'), expected 'normal', 'minimized', 'maximized', 'fullscreen', 'locked-fullscreen' or 'docked'")]
+pub struct InvalidWindowStateError(String);
+
+impl TryFrom<String> for WindowState {
+    type Error = InvalidWindowStateError;
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        match &*s {
+            "normal" => Ok(WindowState::Normal),
+            "minimized" => Ok(WindowState::Minimized),
+            "maximized" => Ok(WindowState::Maximized),
+            "fullscreen" => Ok(WindowState::Fullscreen),
+            "locked-fullscreen" => Ok(WindowState::LockedFullscreen),
+            "docked" => Ok(WindowState::Docked),
+            _ => Err(InvalidWindowStateError(s)),
+        }
+    }
+}
@@ -0,0 +1,471 @@
+use std::path::Path;
+use web_extensions::{
+    backend::*,
+    bookmarks::{self, BookmarkEntry, BookmarkId, Changes, CreateDetails, Destination},
+    downloads::{DownloadOptions, DownloadQuery, DownloadTracker, InterruptReason, State},
+    history::{self, Query, TransitionType},
+    tabs::{CreateProperties, MoveProperties, QueryDetails, UpdateProperties},
+    windows::{CreateData, QueryOptions, UpdateInfo, WindowId},
+    Error, Timestamp,
+};
+
+mod util;
+use util::block_on;
+
+fn current_window_tabs() -> QueryDetails<'static> {
+    QueryDetails {
+        window_id: Some(WindowId::CURRENT),
+        ..Default::default()
+    }
+}
+
+fn urls<'a>(tabs: impl IntoIterator<Item = &'a web_extensions::tabs::Tab>) -> Vec<&'a str> {
+    tabs.into_iter()
+        .map(|tab| tab.url.as_deref().unwrap())
+        .collect()
+}
+
+/// Generic over the backend, like the logic of an extension.
+async fn close_duplicate_tabs(backend: &impl TabsBackend) -> Result<usize, Error> {
+    let tabs = backend.query_tabs(&current_window_tabs()).await?;
+    let mut seen = vec![];
+    let mut duplicates = vec![];
+    for tab in tabs {
+        if seen.contains(&tab.url) {
+            duplicates.extend(tab.id);
+        } else {
+            seen.push(tab.url);
+        }
+    }
+    backend.remove_tabs(&duplicates).await?;
+    Ok(duplicates.len())
+}
+
+#[test]
+fn fake_tabs_and_windows() {
+    let backend = FakeBackend::new();
+    let first = block_on(backend.create_tab(CreateProperties {
+        active: false,
+        url: "https://example.com/",
+    }))
+    .unwrap();
+    assert!(first.active, "the first tab of a window is active");
+    let second = block_on(backend.create_tab(CreateProperties {
+        active: true,
+        url: "https://www.rust-lang.org/",
+    }))
+    .unwrap();
+    assert_eq!(second.index, 1);
+    block_on(backend.duplicate_tab(first.id.unwrap())).unwrap();
+    let tabs = block_on(backend.query_tabs(&current_window_tabs())).unwrap();
+    assert_eq!(
+        urls(&tabs),
+        [
+            "https://example.com/",
+            "https://example.com/",
+            "https://www.rust-lang.org/"
+        ]
+    );
+    assert_eq!(
+        tabs.iter().map(|tab| tab.index).collect::<Vec<_>>(),
+        [0, 1, 2]
+    );
+    assert!(tabs[1].active && !tabs[0].active && !tabs[2].active);
+
+    let details = QueryDetails {
+        url: Some("https://*.rust-lang.org/*"),
+        ..current_window_tabs()
+    };
+    assert_eq!(block_on(backend.query_tabs(&details)).unwrap().len(), 1);
+
+    assert_eq!(block_on(close_duplicate_tabs(&backend)).unwrap(), 1);
+    let tabs = block_on(backend.query_tabs(&current_window_tabs())).unwrap();
+    assert_eq!(
+        urls(&tabs),
+        ["https://example.com/", "https://www.rust-lang.org/"]
+    );
+    assert!(
+        tabs[1].active,
+        "the tab to the right of the removed one is activated"
+    );
+
+    let pinned = block_on(backend.update_tab(
+        second.id.unwrap(),
+        &UpdateProperties {
+            pinned: Some(true),
+            ..Default::default()
+        },
+    ))
+    .unwrap();
+    assert!(pinned.pinned);
+
+    let popup = block_on(backend.create_window(&CreateData {
+        tab_id: second.id,
+        focused: Some(false),
+        ..Default::default()
+    }))
+    .unwrap();
+    assert_eq!(
+        urls(popup.tabs.as_ref().unwrap()),
+        ["https://www.rust-lang.org/"]
+    );
+    let windows = block_on(backend.get_all_windows(&QueryOptions::default())).unwrap();
+    assert_eq!(windows.len(), 2);
+    assert!(windows[0].focused && !windows[1].focused);
+    let all_tabs = block_on(backend.query_tabs(&QueryDetails::default())).unwrap();
+    assert_eq!(all_tabs.len(), 2, "tabs of all windows");
+    assert_eq!(
+        block_on(backend.query_tabs(&current_window_tabs()))
+            .unwrap()
+            .len(),
+        1
+    );
+
+    // Unfocusing the focused window focuses another one.
+    let focus = |window_id: Option<WindowId>, focused| {
+        block_on(backend.update_window(
+            window_id.unwrap(),
+            &UpdateInfo {
+                focused: Some(focused),
+                ..Default::default()
+            },
+        ))
+        .unwrap()
+    };
+    assert!(!focus(windows[0].id, false).focused);
+    let last_focused = block_on(backend.get_last_focused_window(&QueryOptions::default())).unwrap();
+    assert_eq!(last_focused.id, popup.id);
+    assert!(last_focused.focused);
+    assert!(focus(windows[0].id, true).focused);
+    assert_eq!(
+        block_on(backend.get_current_window(&QueryOptions::default()))
+            .unwrap()
+            .id,
+        windows[0].id
+    );
+
+    block_on(backend.move_tabs(
+        &[second.id.unwrap()],
+        MoveProperties {
+            index: 0,
+            window_id: windows[0].id,
+        },
+    ))
+    .unwrap();
+    let windows = block_on(backend.get_all_windows(&QueryOptions {
+        populate: Some(true),
+        ..Default::default()
+    }))
+    .unwrap();
+    assert_eq!(windows.len(), 1, "the empty window is closed");
+    assert_eq!(
+        urls(windows[0].tabs.as_ref().unwrap()),
+        ["https://www.rust-lang.org/", "https://example.com/"]
+    );
+
+    assert!(matches!(
+        block_on(backend.get_tab(99.into())),
+        Err(Error::Backend(message)) if message == "No tab with id: 99."
+    ));
+    block_on(backend.remove_window(WindowId::CURRENT)).unwrap();
+    assert!(block_on(backend.get_current_window(&QueryOptions::default())).is_err());
+}
+
+#[test]
+fn fake_bookmarks() {
+    let backend = FakeBackend::new();
+    let bar = BookmarkId::from("1");
+    let folder = block_on(backend.create_bookmark(&CreateDetails {
+        parent_id: Some(&bar),
+        title: Some("Rust"),
+        ..Default::default()
+    }))
+    .unwrap();
+    let create = |title, url| {
+        block_on(backend.create_bookmark(&CreateDetails {
+            parent_id: Some(&folder.id),
+            title: Some(title),
+            url: Some(url),
+            ..Default::default()
+        }))
+        .unwrap()
+    };
+    let book = create("The Book", "https://doc.rust-lang.org/book/");
+    let docs = create("Docs", "https://docs.rs/");
+    let titles = |id| {
+        block_on(backend.get_bookmark_children(id))
+            .unwrap()
+            .into_iter()
+            .map(|node| node.title)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(titles(&folder.id), ["The Book", "Docs"]);
+
+    block_on(backend.move_bookmark(
+        &book.id,
+        &Destination {
+            parent_id: None,
+            index: Some(2),
+        },
+    ))
+    .unwrap();
+    assert_eq!(titles(&folder.id), ["Docs", "The Book"]);
+
+    let updated = block_on(backend.update_bookmark(
+        &docs.id,
+        &Changes {
+            title: Some("docs.rs"),
+            url: None,
+        },
+    ))
+    .unwrap();
+    assert_eq!(updated.title, "docs.rs");
+    let found = block_on(backend.search_bookmarks(&"RUST book".into())).unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].id, book.id);
+
+    let tree = block_on(backend.get_bookmark_tree()).unwrap();
+    assert_eq!(
+        tree[0].folder_path(&docs.id).as_deref(),
+        Some("Bookmarks bar/Rust")
+    );
+    assert!(block_on(backend.remove_bookmark(&folder.id)).is_err());
+    assert!(block_on(backend.remove_bookmark_tree(&bar)).is_err());
+    assert!(block_on(backend.move_bookmark(
+        &folder.id,
+        &Destination {
+            parent_id: Some(&docs.id),
+            index: None,
+        },
+    ))
+    .is_err());
+    let exported =
+        BookmarkEntry::from(&block_on(backend.get_bookmark_sub_tree(&folder.id)).unwrap()[0]);
+    block_on(backend.remove_bookmark_tree(&folder.id)).unwrap();
+    let count = || {
+        block_on(backend.get_bookmark_tree()).unwrap()[0]
+            .count()
+            .bookmarks
+    };
+    assert_eq!(count(), 0);
+
+    let other = BookmarkId::from("2");
+    let imported = block_on(bookmarks::import_with(&backend, &other, &[exported])).unwrap();
+    assert_eq!(titles(&imported[0].id), ["docs.rs", "The Book"]);
+    assert_eq!(count(), 2);
+}
+
+#[test]
+fn fake_bookmarks_apply_diff() {
+    fn create(
+        backend: &FakeBackend,
+        parent: &BookmarkId,
+        title: &str,
+        url: Option<&str>,
+    ) -> BookmarkId {
+        block_on(backend.create_bookmark(&CreateDetails {
+            parent_id: Some(parent),
+            title: Some(title),
+            url,
+            ..Default::default()
+        }))
+        .unwrap()
+        .id
+    }
+    fn build(backend: &FakeBackend) -> (BookmarkId, BookmarkId, BookmarkId) {
+        let rust = create(backend, &"1".into(), "Rust", None);
+        let book = create(
+            backend,
+            &rust,
+            "The Book",
+            Some("https://doc.rust-lang.org/book/"),
+        );
+        let docs = create(backend, &rust, "Docs", Some("https://docs.rs/"));
+        let crates = create(backend, &"2".into(), "Crates", Some("https://crates.io/"));
+        (book, docs, crates)
+    }
+    let tree = |backend: &FakeBackend| block_on(backend.get_bookmark_tree()).unwrap().remove(0);
+
+    let old = FakeBackend::new();
+    build(&old);
+    let new = FakeBackend::new();
+    let (book, docs, crates) = build(&new);
+    let other = BookmarkId::from("2");
+    block_on(new.move_bookmark(
+        &book,
+        &Destination {
+            parent_id: Some(&other),
+            index: Some(0),
+        },
+    ))
+    .unwrap();
+    block_on(new.update_bookmark(
+        &docs,
+        &Changes {
+            title: Some("docs.rs"),
+            url: None,
+        },
+    ))
+    .unwrap();
+    let blog = create(&new, &"1".into(), "Blog", None);
+    create(
+        &new,
+        &blog,
+        "This Week in Rust",
+        Some("https://this-week-in-rust.org/"),
+    );
+    block_on(new.remove_bookmark(&crates)).unwrap();
+
+    let operations = bookmarks::diff(&tree(&old), &tree(&new));
+    block_on(bookmarks::apply_diff_with(&old, &operations)).unwrap();
+    assert_eq!(
+        BookmarkEntry::from(&tree(&old)),
+        BookmarkEntry::from(&tree(&new))
+    );
+}
+
+#[test]
+fn fake_history() {
+    let backend = FakeBackend::new();
+    let hour = 60.0 * 60.0 * 1000.0;
+    backend.set_now(Timestamp::from_millis(100.0 * hour));
+    backend.visit(
+        "https://www.rust-lang.org/",
+        Some("Rust"),
+        TransitionType::Typed,
+    );
+    backend.set_now(Timestamp::from_millis(110.0 * hour));
+    block_on(backend.add_url("https://docs.rs/")).unwrap();
+    block_on(backend.add_url("https://www.rust-lang.org/")).unwrap();
+
+    let items = block_on(backend.search_history(&"".into())).unwrap();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0].url.as_deref(), Some("https://www.rust-lang.org/"));
+    assert_eq!(items[0].visit_count, Some(2));
+    assert_eq!(items[0].typed_count, Some(1));
+    assert_eq!(
+        block_on(backend.search_history(&"rust".into()))
+            .unwrap()
+            .len(),
+        1
+    );
+    let query = Query {
+        start_time: Some(Timestamp::from_millis(105.0 * hour)),
+        max_results: Some(1),
+        ..Default::default()
+    };
+    assert_eq!(block_on(backend.search_history(&query)).unwrap().len(), 1);
+
+    block_on(backend.delete_range(
+        Timestamp::from_millis(109.0 * hour),
+        Timestamp::from_millis(111.0 * hour),
+    ))
+    .unwrap();
+    let visits = block_on(backend.get_visits("https://www.rust-lang.org/")).unwrap();
+    assert_eq!(visits.len(), 1);
+    assert_eq!(visits[0].transition, TransitionType::Typed);
+    assert!(block_on(backend.get_visits("https://docs.rs/"))
+        .unwrap()
+        .is_empty());
+
+    backend.set_now(Timestamp::from_millis(112.0 * hour));
+    backend.visit("https://crates.io/", None, TransitionType::Link);
+    let query = Query {
+        start_time: Some(Timestamp::UNIX_EPOCH),
+        max_results: Some(1),
+        ..Default::default()
+    };
+    let all = block_on(history::search_all_with(&backend, query)).unwrap();
+    let urls: Vec<_> = all.iter().filter_map(|item| item.url.as_deref()).collect();
+    assert_eq!(urls, ["https://crates.io/", "https://www.rust-lang.org/"]);
+}
+
+#[test]
+fn fake_downloads() {
+    let backend = FakeBackend::new();
+    let options = DownloadOptions {
+        url: "https://example.com/files/report.pdf?version=2",
+        ..Default::default()
+    };
+    let first = block_on(backend.download(&options)).unwrap();
+    let second = block_on(backend.download(&options)).unwrap();
+    let items = block_on(backend.search_downloads(&DownloadQuery::default())).unwrap();
+    assert_eq!(items[0].filename, Path::new("report.pdf"));
+    assert_eq!(items[1].filename, Path::new("report (1).pdf"));
+
+    block_on(backend.pause_download(first)).unwrap();
+    block_on(backend.resume_download(first)).unwrap();
+    backend
+        .update_download(first, |item| {
+            item.bytes_received = 1024;
+            item.total_bytes = 1024;
+            item.state = State::Complete;
+        })
+        .unwrap();
+    block_on(backend.cancel_download(second)).unwrap();
+    let interrupted =
+        block_on(backend.search_downloads(&DownloadQuery::default().state(State::Interrupted)))
+            .unwrap();
+    assert_eq!(interrupted.len(), 1);
+    assert_eq!(interrupted[0].error, Some(InterruptReason::UserCanceled));
+    assert!(block_on(backend.resume_download(second)).is_err());
+
+    let ordered = block_on(
+        backend.search_downloads(&DownloadQuery::default().order_by(vec!["-bytesReceived"])),
+    )
+    .unwrap();
+    assert_eq!(ordered[0].id, first);
+    assert!(block_on(backend.search_downloads(&DownloadQuery::default().url_regex(".*"))).is_err());
+
+    block_on(backend.remove_download_file(first)).unwrap();
+    assert_eq!(
+        block_on(backend.erase_downloads(&"report".into())).unwrap(),
+        [first, second]
+    );
+}
+
+#[test]
+fn fake_download_tracker() {
+    let backend = FakeBackend::new();
+    let download = |url| {
+        block_on(backend.download(&DownloadOptions {
+            url,
+            ..Default::default()
+        }))
+        .unwrap()
+    };
+    let photo = download("https://example.com/files/photo.jpg");
+    let notes = download("https://example.com/files/notes.txt");
+    let video = download("https://example.com/files/photo-video.mp4");
+    let tracker = block_on(DownloadTracker::start_with(backend, &"photo".into())).unwrap();
+    assert!(tracker.get(photo).is_some() && tracker.get(notes).is_none());
+    assert!(tracker.get(video).is_some());
+
+    let backend = tracker.backend();
+    backend.set_now(Timestamp::from_millis(2000.0));
+    let update = |id, bytes, state| {
+        backend
+            .update_download(id, |item| {
+                item.bytes_received = bytes;
+                item.total_bytes = 4096;
+                item.state = state;
+            })
+            .unwrap()
+    };
+    update(photo, 1024, State::InProgress);
+    update(notes, 1024, State::InProgress);
+    block_on(tracker.refresh()).unwrap();
+    let progress = tracker.progress(photo).unwrap();
+    assert_eq!(progress.bytes_per_second, Some(512.0));
+    assert_eq!(progress.fraction, Some(0.25));
+    assert!(tracker.get(notes).is_none());
+
+    update(photo, 4096, State::Complete);
+    block_on(tracker.refresh()).unwrap();
+    assert_eq!(tracker.get(photo).unwrap().state, State::Complete);
+
+    block_on(backend.erase_downloads(&DownloadQuery::default().id(video))).unwrap();
+    block_on(tracker.refresh()).unwrap();
+    assert!(tracker.get(video).is_none());
+    assert_eq!(tracker.items().len(), 1);
+}
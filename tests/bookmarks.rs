@@ -0,0 +1,324 @@
+use web_extensions::{bookmarks::*, Timestamp};
+
+mod util;
+use util::*;
+
+#[test]
+fn bookmark_tree_node_deserialize() {
+    let json = r#"{
+        "id": "0",
+        "title": "",
+        "dateAdded": 1668000000000,
+        "children": [{
+            "id": "1",
+            "parentId": "0",
+            "index": 0,
+            "title": "Bookmarks bar",
+            "children": [{
+                "id": "5",
+                "parentId": "1",
+                "index": 0,
+                "title": "Rust",
+                "url": "https://www.rust-lang.org/"
+            }]
+        }]
+    }"#;
+    let root: BookmarkTreeNode = serde_json::from_str(json).unwrap();
+    assert_eq!(root.id, BookmarkId::from("0"));
+    assert_eq!(root.parent_id, None);
+    let bar = &root.children.as_ref().unwrap()[0];
+    assert_eq!(bar.parent_id, Some(BookmarkId::from("0")));
+    let bookmark = &bar.children.as_ref().unwrap()[0];
+    assert_eq!(bookmark.id.as_str(), "5");
+    assert_eq!(bookmark.url.as_deref(), Some("https://www.rust-lang.org/"));
+}
+
+#[test]
+fn create_details_serialize() {
+    let parent_id = BookmarkId::from("1");
+    assert_json_serialize_test_cases(&[
+        JSONSerdeTestCase {
+            value: CreateDetails {
+                parent_id: Some(&parent_id),
+                index: Some(0),
+                title: Some("Rust"),
+                url: Some("https://www.rust-lang.org/"),
+            },
+            json: r#"{"parentId":"1","index":0,"title":"Rust","url":"https://www.rust-lang.org/"}"#,
+        },
+        JSONSerdeTestCase {
+            value: CreateDetails {
+                title: Some("Folder"),
+                ..Default::default()
+            },
+            json: r#"{"parentId":null,"index":null,"title":"Folder","url":null}"#,
+        },
+    ])
+}
+
+#[test]
+fn destination_serialize() {
+    let parent_id = BookmarkId::from("2");
+    assert_json_serialize_eq(
+        &Destination {
+            parent_id: Some(&parent_id),
+            index: None,
+        },
+        r#"{"parentId":"2","index":null}"#,
+    );
+}
+
+#[test]
+fn remove_info_deserialize() {
+    let json = r#"{
+        "parentId": "1",
+        "index": 3,
+        "node": { "id": "7", "parentId": "1", "index": 3, "title": "Old", "url": "https://example.com/" }
+    }"#;
+    let info: RemoveInfo = serde_json::from_str(json).unwrap();
+    assert_eq!(info.parent_id, BookmarkId::from("1"));
+    assert_eq!(info.index, 3);
+    assert_eq!(info.node.id, BookmarkId::from("7"));
+}
+
+#[test]
+fn move_and_reorder_info_deserialize() {
+    assert_json_deserialize_eq(
+        r#"{"parentId":"2","index":0,"oldParentId":"1","oldIndex":4}"#,
+        &MoveInfo {
+            parent_id: BookmarkId::from("2"),
+            index: 0,
+            old_parent_id: BookmarkId::from("1"),
+            old_index: 4,
+        },
+    );
+    assert_json_deserialize_eq(
+        r#"{"childIds":["5","3","4"]}"#,
+        &ReorderInfo {
+            child_ids: vec!["5".into(), "3".into(), "4".into()],
+        },
+    );
+    assert_json_deserialize_eq(
+        r#"{"title":"Folder"}"#,
+        &ChangeInfo {
+            title: "Folder".to_owned(),
+            url: None,
+        },
+    );
+}
+
+fn tree(json: &str) -> BookmarkTreeNode {
+    serde_json::from_str(json).unwrap()
+}
+
+const TREE: &str = r#"{
+    "id": "0", "title": "", "children": [
+        { "id": "1", "parentId": "0", "title": "Bookmarks Bar", "children": [
+            { "id": "10", "parentId": "1", "title": "Work", "children": [
+                { "id": "11", "parentId": "10", "title": "Infra", "children": [
+                    { "id": "12", "parentId": "11", "title": "CI", "url": "https://ci.example.com/" }
+                ] },
+                { "id": "13", "parentId": "10", "title": "Docs", "url": "https://docs.rs/" }
+            ] },
+            { "id": "14", "parentId": "1", "title": "Rust docs", "url": "https://docs.rs/" }
+        ] },
+        { "id": "2", "parentId": "0", "title": "Other Bookmarks", "children": [] }
+    ]
+}"#;
+
+fn ids<'a>(nodes: impl Iterator<Item = &'a BookmarkTreeNode>) -> Vec<&'a str> {
+    nodes.map(|node| node.id.as_str()).collect()
+}
+
+#[test]
+fn tree_traversal() {
+    let root = tree(TREE);
+    assert_eq!(
+        ids(root.iter_depth_first()),
+        ["0", "1", "10", "11", "12", "13", "14", "2"]
+    );
+    assert_eq!(
+        ids(root.iter_breadth_first()),
+        ["0", "1", "2", "10", "14", "11", "13", "12"]
+    );
+    assert_eq!(root.find_by_id(&"13".into()).unwrap().title, "Docs");
+    assert_eq!(root.find_by_id(&"99".into()), None);
+    assert_eq!(ids(root.find_by_url("https://docs.rs/")), ["13", "14"]);
+    assert_eq!(
+        root.folder_path(&"12".into()).as_deref(),
+        Some("Bookmarks Bar/Work/Infra")
+    );
+    assert_eq!(root.folder_path(&"1".into()).as_deref(), Some(""));
+    assert_eq!(
+        root.count(),
+        NodeCount {
+            bookmarks: 3,
+            folders: 5
+        }
+    );
+    let duplicates = root.duplicate_urls();
+    assert_eq!(duplicates.len(), 1);
+    assert_eq!(
+        ids(duplicates["https://docs.rs/"].iter().copied()),
+        ["13", "14"]
+    );
+}
+
+#[test]
+fn tree_diff() {
+    let old = tree(TREE);
+    let new = tree(
+        r#"{
+        "id": "0", "title": "", "children": [
+            { "id": "1", "parentId": "0", "title": "Bookmarks Bar", "children": [
+                { "id": "14", "parentId": "1", "title": "Rust documentation", "url": "https://docs.rs/" },
+                { "id": "10", "parentId": "1", "title": "Work", "children": [
+                    { "id": "20", "parentId": "10", "title": "Monitoring", "children": [
+                        { "id": "12", "parentId": "20", "title": "CI", "url": "https://ci.example.com/" }
+                    ] }
+                ] }
+            ] },
+            { "id": "2", "parentId": "0", "title": "Other Bookmarks", "children": [] }
+        ]
+    }"#,
+    );
+    assert_eq!(
+        diff(&old, &new),
+        [
+            Operation::Move {
+                id: "14".into(),
+                parent_id: "1".into(),
+                index: 0
+            },
+            Operation::Create {
+                id: "20".into(),
+                parent_id: "10".into(),
+                index: 0,
+                title: "Monitoring".to_owned(),
+                url: None
+            },
+            Operation::Move {
+                id: "12".into(),
+                parent_id: "20".into(),
+                index: 0
+            },
+            Operation::Update {
+                id: "14".into(),
+                title: "Rust documentation".to_owned(),
+                url: Some("https://docs.rs/".to_owned())
+            },
+            Operation::Remove {
+                id: "11".into(),
+                recursive: true
+            },
+            Operation::Remove {
+                id: "13".into(),
+                recursive: false
+            },
+        ]
+    );
+    assert_eq!(diff(&old, &old), []);
+}
+
+fn bookmark(title: &str, url: &str, date_added: f64) -> BookmarkEntry {
+    BookmarkEntry {
+        title: title.to_owned(),
+        url: Some(url.to_owned()),
+        date_added: Some(Timestamp::from_millis(date_added)),
+        last_modified: None,
+        children: vec![],
+    }
+}
+
+fn entries() -> Vec<BookmarkEntry> {
+    vec![BookmarkEntry {
+        title: "Work & Play".to_owned(),
+        url: None,
+        date_added: Some(Timestamp::from_millis(1668000000000.0)),
+        last_modified: Some(Timestamp::from_millis(1668000100000.0)),
+        children: vec![
+            bookmark(
+                "<Rust>",
+                "https://www.rust-lang.org/?a=1&b=\"2\"",
+                1668000001000.0,
+            ),
+            BookmarkEntry {
+                title: "Empty".to_owned(),
+                url: None,
+                date_added: None,
+                last_modified: None,
+                children: vec![],
+            },
+        ],
+    }]
+}
+
+#[test]
+fn netscape_html_roundtrip() {
+    let html = to_netscape_html(&entries());
+    assert!(html.starts_with("<!DOCTYPE NETSCAPE-Bookmark-file-1>"));
+    assert!(html.contains(
+        r#"<DT><A HREF="https://www.rust-lang.org/?a=1&amp;b=&quot;2&quot;" ADD_DATE="1668000001">&lt;Rust&gt;</A>"#
+    ));
+    assert_eq!(from_netscape_html(&html).unwrap(), entries());
+}
+
+#[test]
+fn netscape_html_parse_lenient() {
+    let html = r#"<!DOCTYPE NETSCAPE-Bookmark-file-1>
+<meta http-equiv="Content-Type" content="text/html; charset=UTF-8">
+<title>Bookmarks</title>
+<h1>Bookmarks Menu</h1>
+<dl><p>
+    <dt><h3 add_date="1668000000" personal_toolbar_folder="true">Toolbar</h3>
+    <dl><p>
+        <dt><a href='https://example.com/a>b' ICON="data:image/png;base64,AAAA">Caf&#233; &amp; more</a>
+        <dd>A description
+    </dl><p>
+    <dt><a href="https://example.org/">Top</a>
+"#;
+    let entries = from_netscape_html(html).unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].title, "Toolbar");
+    assert_eq!(
+        entries[0].date_added,
+        Some(Timestamp::from_millis(1668000000000.0))
+    );
+    assert_eq!(entries[0].children[0].title, "Café & more");
+    assert_eq!(
+        entries[0].children[0].url.as_deref(),
+        Some("https://example.com/a>b")
+    );
+    assert_eq!(entries[1].url.as_deref(), Some("https://example.org/"));
+
+    assert!(matches!(
+        from_netscape_html("<html><body>Hello</body></html>"),
+        Err(web_extensions::Error::InvalidBookmarkFile)
+    ));
+}
+
+#[test]
+fn json_roundtrip() {
+    let json = to_json(&entries()).unwrap();
+    assert!(json.starts_with(r#"{"version":1,"bookmarks":[{"title":"Work & Play","url":null,"#));
+    assert_eq!(from_json(&json).unwrap(), entries());
+    assert!(matches!(
+        from_json(r#"{"version":2,"bookmarks":[]}"#),
+        Err(web_extensions::Error::UnsupportedSchemaVersion {
+            found: 2,
+            supported: 1
+        })
+    ));
+}
+
+#[test]
+fn bookmark_entry_from_tree() {
+    let root = tree(TREE);
+    let entry = BookmarkEntry::from(root.find_by_id(&"10".into()).unwrap());
+    assert_eq!(entry.title, "Work");
+    assert_eq!(entry.children.len(), 2);
+    assert_eq!(
+        entry.children[0].children[0].url.as_deref(),
+        Some("https://ci.example.com/")
+    );
+}
@@ -0,0 +1,45 @@
+use web_extensions::{browser_from_user_agent, Namespace};
+
+#[test]
+fn detect_browser_from_user_agent() {
+    let cases = [
+        (
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/107.0.0.0 Safari/537.36 Edg/107.0.1418.35",
+            Some(("Edge", "107.0.1418.35")),
+        ),
+        (
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/106.0.0.0 Safari/537.36 OPR/92.0.0.0",
+            Some(("Opera", "92.0.0.0")),
+        ),
+        (
+            "Mozilla/5.0 (X11; Linux x86_64; rv:106.0) Gecko/20100101 Firefox/106.0",
+            Some(("Firefox", "106.0")),
+        ),
+        (
+            "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chromium/107.0.5304.87 Chrome/107.0.5304.87 Safari/537.36",
+            Some(("Chromium", "107.0.5304.87")),
+        ),
+        (
+            "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/107.0.0.0 Safari/537.36",
+            Some(("Chrome", "107.0.0.0")),
+        ),
+        (
+            "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/16.1 Safari/605.1.15",
+            Some(("Safari", "16.1")),
+        ),
+        ("curl/7.86.0", None),
+    ];
+    for (user_agent, expected) in cases {
+        assert_eq!(
+            browser_from_user_agent(user_agent),
+            expected,
+            "{user_agent}"
+        );
+    }
+}
+
+#[test]
+fn namespace_name() {
+    assert_eq!(Namespace::Browser.name(), "browser");
+    assert_eq!(Namespace::Chrome.name(), "chrome");
+}
@@ -0,0 +1,259 @@
+use web_extensions::{downloads::*, Timestamp};
+
+mod util;
+use util::*;
+
+#[test]
+fn download_options_serialize() {
+    assert_json_serialize_test_cases(&[JSONSerdeTestCase {
+        value: DownloadOptions {
+            url: "https://example.com/report",
+            body: Some("format=pdf"),
+            conflict_action: Some(FilenameConflictAction::Uniquify),
+            filename: Some("reports/report.pdf"),
+            headers: Some(vec![HeaderNameValuePair {
+                name: "Accept",
+                value: "application/pdf",
+            }]),
+            method: Some(HttpMethod::Post),
+            save_as: Some(false),
+        },
+        json: concat!(
+            r#"{"url":"https://example.com/report","body":"format=pdf","conflictAction":"uniquify","#,
+            r#""filename":"reports/report.pdf","headers":[{"name":"Accept","value":"application/pdf"}],"#,
+            r#""method":"POST","saveAs":false}"#
+        ),
+    }])
+}
+
+#[test]
+fn interrupt_reason_serde() {
+    assert_json_serde_test_cases(&[
+        JSONSerdeTestCase {
+            value: InterruptReason::FileNoSpace,
+            json: r#""FILE_NO_SPACE""#,
+        },
+        JSONSerdeTestCase {
+            value: InterruptReason::ServerCrossOriginRedirect,
+            json: r#""SERVER_CROSS_ORIGIN_REDIRECT""#,
+        },
+        JSONSerdeTestCase {
+            value: InterruptReason::UserCanceled,
+            json: r#""USER_CANCELED""#,
+        },
+    ])
+}
+
+#[test]
+fn state_serde() {
+    assert_json_serde_test_cases(&[
+        JSONSerdeTestCase {
+            value: State::InProgress,
+            json: r#""in_progress""#,
+        },
+        JSONSerdeTestCase {
+            value: State::Interrupted,
+            json: r#""interrupted""#,
+        },
+        JSONSerdeTestCase {
+            value: State::Complete,
+            json: r#""complete""#,
+        },
+    ])
+}
+
+#[test]
+fn danger_type_deserialize_unknown() {
+    assert_json_deserialize_eq(r#""sensitiveContentWarning""#, &DangerType::Other);
+    assert_json_deserialize_eq(r#""safe""#, &DangerType::Safe);
+}
+
+#[test]
+fn interrupt_reason_deserialize_unknown() {
+    assert_json_deserialize_eq(r#""FILE_TOO_SMALL""#, &InterruptReason::Other);
+    assert_json_deserialize_eq(r#""USER_CANCELED""#, &InterruptReason::UserCanceled);
+}
+
+#[test]
+fn download_query_rejects_unknown_values() {
+    assert!(serde_json::to_string(&DownloadQuery::default().danger(DangerType::Other)).is_err());
+    assert!(
+        serde_json::to_string(&DownloadQuery::default().error(InterruptReason::Other)).is_err()
+    );
+    let query = serde_json::to_value(DownloadQuery::default().error(InterruptReason::UserCanceled));
+    assert_eq!(query.unwrap()["error"], "USER_CANCELED");
+}
+
+#[test]
+fn download_item_deserialize() {
+    let json = r#"{
+        "bytesReceived": 1024,
+        "canResume": true,
+        "danger": "safe",
+        "error": "NETWORK_FAILED",
+        "exists": true,
+        "fileSize": -1,
+        "filename": "/home/user/Downloads/archive.zip",
+        "finalUrl": "https://cdn.example.com/archive.zip",
+        "id": 42,
+        "incognito": false,
+        "mime": "application/zip",
+        "paused": false,
+        "referrer": "",
+        "startTime": "2022-11-09T10:15:30.000Z",
+        "state": "interrupted",
+        "totalBytes": 4096,
+        "url": "https://example.com/archive.zip"
+    }"#;
+    let item: DownloadItem = serde_json::from_str(json).unwrap();
+    assert_eq!(item.id, DownloadId::from(42));
+    assert_eq!(item.state, State::Interrupted);
+    assert_eq!(item.error, Some(InterruptReason::NetworkFailed));
+    assert_eq!(item.bytes_received, 1024);
+    assert_eq!(item.file_size, -1);
+    assert_eq!(item.end_time, None);
+}
+
+#[test]
+fn download_query_builder() {
+    let owned = DownloadQuery::default()
+        .state(State::Complete)
+        .filename_regex(r"\.pdf$")
+        .order_by(vec!["-startTime"])
+        .limit(10);
+    let mut borrowed = DownloadQuery::default();
+    borrowed
+        .set_state(State::Complete)
+        .set_filename_regex(r"\.pdf$")
+        .set_order_by(vec!["-startTime"])
+        .set_limit(10);
+    assert_eq!(owned, borrowed);
+
+    let json = serde_json::to_value(&owned).unwrap();
+    assert_eq!(json["state"], "complete");
+    assert_eq!(json["filenameRegex"], r"\.pdf$");
+    assert_eq!(json["orderBy"][0], "-startTime");
+    assert_eq!(json["limit"], 10);
+    assert!(json["totalBytesGreater"].is_null());
+}
+
+#[test]
+fn download_delta_deserialize() {
+    let json = r#"{
+        "id": 42,
+        "state": { "previous": "in_progress", "current": "complete" },
+        "endTime": { "current": "2022-11-09T10:16:00.000Z" },
+        "totalBytes": { "previous": -1, "current": 4096 }
+    }"#;
+    let delta: DownloadDelta = serde_json::from_str(json).unwrap();
+    assert_eq!(delta.id, DownloadId::from(42));
+    assert_eq!(
+        delta.state,
+        Some(Delta {
+            previous: Some(State::InProgress),
+            current: Some(State::Complete),
+        })
+    );
+    assert_eq!(
+        delta.end_time.unwrap().current,
+        Some(Timestamp::from_millis(1667988960000.0))
+    );
+    assert_eq!(delta.total_bytes.unwrap().current, Some(4096));
+    assert_eq!(delta.paused, None);
+}
+
+fn in_progress_item(bytes_received: u64) -> DownloadItem {
+    serde_json::from_value(serde_json::json!({
+        "bytesReceived": bytes_received,
+        "canResume": false,
+        "danger": "safe",
+        "exists": true,
+        "fileSize": -1,
+        "filename": "/home/user/Downloads/archive.zip",
+        "finalUrl": "https://example.com/archive.zip",
+        "id": 7,
+        "incognito": false,
+        "mime": "application/zip",
+        "paused": false,
+        "referrer": "",
+        "startTime": "2022-11-09T10:15:30.000Z",
+        "state": "in_progress",
+        "totalBytes": 4000,
+        "url": "https://example.com/archive.zip"
+    }))
+    .unwrap()
+}
+
+#[test]
+fn download_delta_apply() {
+    let mut item = in_progress_item(0);
+    let delta: DownloadDelta = serde_json::from_str(
+        r#"{
+            "id": 7,
+            "state": { "previous": "in_progress", "current": "interrupted" },
+            "error": { "current": "NETWORK_FAILED" },
+            "canResume": { "previous": false, "current": true }
+        }"#,
+    )
+    .unwrap();
+    delta.apply(&mut item);
+    assert_eq!(item.state, State::Interrupted);
+    assert_eq!(item.error, Some(InterruptReason::NetworkFailed));
+    assert!(item.can_resume);
+    assert_eq!(item.total_bytes, 4000);
+}
+
+#[test]
+fn tracked_downloads_progress_and_transitions() {
+    use futures_core::Stream;
+    use std::{pin::Pin, task, time::Duration};
+
+    let id = DownloadId::from(7);
+    let mut downloads = TrackedDownloads::new();
+    let mut transitions = downloads.transitions();
+    downloads.insert(in_progress_item(1000), 0.0);
+    assert_eq!(downloads.progress(id).unwrap().bytes_per_second, None);
+
+    assert_eq!(downloads.insert(in_progress_item(2000), 500.0), None);
+    let progress = downloads.progress(id).unwrap();
+    assert_eq!(progress.fraction, Some(0.5));
+    assert_eq!(progress.bytes_per_second, Some(2000.0));
+    assert_eq!(progress.eta, Some(Duration::from_secs(1)));
+
+    // Changes that do not concern the progress keep the speed.
+    let delta: DownloadDelta =
+        serde_json::from_str(r#"{ "id": 7, "filename": { "current": "/tmp/file.zip" } }"#).unwrap();
+    assert_eq!(downloads.apply(&delta, 600.0), None);
+    assert_eq!(
+        downloads.progress(id).unwrap().bytes_per_second,
+        Some(2000.0)
+    );
+    assert_eq!(downloads.insert(in_progress_item(2500), 750.0), None);
+    assert_eq!(
+        downloads.progress(id).unwrap().bytes_per_second,
+        Some(2000.0)
+    );
+
+    let delta: DownloadDelta = serde_json::from_str(
+        r#"{ "id": 7, "state": { "previous": "in_progress", "current": "complete" } }"#,
+    )
+    .unwrap();
+    let transition = StateTransition {
+        id,
+        from: State::InProgress,
+        to: State::Complete,
+    };
+    assert_eq!(downloads.apply(&delta, 1000.0), Some(transition));
+    assert_eq!(downloads.progress(id).unwrap().bytes_per_second, None);
+
+    let waker = noop_waker();
+    let mut cx = task::Context::from_waker(&waker);
+    assert_eq!(
+        Pin::new(&mut transitions).poll_next(&mut cx),
+        task::Poll::Ready(Some(transition))
+    );
+    assert!(Pin::new(&mut transitions).poll_next(&mut cx).is_pending());
+
+    assert!(downloads.remove(id).is_some());
+    assert_eq!(downloads.progress(id), None);
+}
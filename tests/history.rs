@@ -0,0 +1,288 @@
+use web_extensions::{history::*, Timestamp};
+
+mod util;
+use util::*;
+
+#[test]
+fn transition_type_serde() {
+    assert_json_serde_test_cases(&[
+        JSONSerdeTestCase {
+            value: TransitionType::Link,
+            json: r#""link""#,
+        },
+        JSONSerdeTestCase {
+            value: TransitionType::AutoBookmark,
+            json: r#""auto_bookmark""#,
+        },
+        JSONSerdeTestCase {
+            value: TransitionType::KeywordGenerated,
+            json: r#""keyword_generated""#,
+        },
+    ])
+}
+
+#[test]
+fn transition_type_deserialize_unknown() {
+    assert_json_deserialize_eq(r#""from_address_bar""#, &TransitionType::Other);
+    assert_json_deserialize_eq(r#""reload""#, &TransitionType::Reload);
+}
+
+#[test]
+fn visit_item_deserialize() {
+    let json = r#"{
+        "id": "12",
+        "visitId": "345",
+        "visitTime": 1668000000123.456,
+        "referringVisitId": "0",
+        "transition": "typed",
+        "isLocal": true
+    }"#;
+    assert_json_deserialize_eq(
+        json,
+        &VisitItem {
+            id: "12".to_owned(),
+            visit_id: "345".to_owned(),
+            visit_time: Some(Timestamp::from_millis(1668000000123.456)),
+            referring_visit_id: "0".to_owned(),
+            transition: TransitionType::Typed,
+            is_local: Some(true),
+        },
+    );
+}
+
+#[test]
+fn removed_info_deserialize() {
+    assert_json_deserialize_eq(
+        r#"{"allHistory":false,"urls":["https://example.com/"]}"#,
+        &RemovedInfo {
+            all_history: false,
+            urls: Some(vec!["https://example.com/".to_owned()]),
+        },
+    );
+    assert_json_deserialize_eq(
+        r#"{"allHistory":true}"#,
+        &RemovedInfo {
+            all_history: true,
+            urls: None,
+        },
+    );
+}
+
+fn history_item(
+    id: &str,
+    url: &str,
+    last_visit_time: f64,
+    visits: usize,
+    typed: usize,
+) -> HistoryItem {
+    HistoryItem {
+        id: id.to_owned(),
+        last_visit_time: Some(Timestamp::from_millis(last_visit_time)),
+        title: None,
+        typed_count: Some(typed),
+        url: Some(url.to_owned()),
+        visit_count: Some(visits),
+    }
+}
+
+#[test]
+fn registrable_domains() {
+    for (url, domain) in [
+        ("https://www.example.com/path?q=1", Some("example.com")),
+        (
+            "https://a.b.shop.example.co.uk:8443/",
+            Some("example.co.uk"),
+        ),
+        ("http://user:pw@Docs.RS/", Some("docs.rs")),
+        (
+            "https://rust-lang.github.io/book",
+            Some("rust-lang.github.io"),
+        ),
+        ("http://localhost:8080/", Some("localhost")),
+        ("http://192.168.0.1/admin", Some("192.168.0.1")),
+        ("http://[::1]:3000/", Some("::1")),
+        ("file:///tmp/a.txt", None),
+        ("about:blank", None),
+        ("chrome://settings", None),
+        ("moz-extension://0a1b2c3d/popup.html", None),
+        ("chrome-extension://abcdefghijklmnop/options.html", None),
+    ] {
+        assert_eq!(registrable_domain(url).as_deref(), domain, "{url}");
+    }
+}
+
+#[test]
+fn group_items_by_domain() {
+    let items = [
+        history_item("1", "https://www.example.com/a", 1000.0, 3, 1),
+        history_item("2", "https://blog.example.com/b", 3000.0, 2, 0),
+        history_item("3", "https://docs.rs/", 2000.0, 10, 5),
+        history_item("4", "chrome://settings", 4000.0, 1, 1),
+    ];
+    let summaries = group_by_domain(&items);
+    assert_eq!(
+        summaries,
+        [
+            DomainSummary {
+                domain: "docs.rs".to_owned(),
+                pages: 1,
+                visit_count: 10,
+                typed_count: 5,
+                last_visit_time: Some(Timestamp::from_millis(2000.0)),
+            },
+            DomainSummary {
+                domain: "example.com".to_owned(),
+                pages: 2,
+                visit_count: 5,
+                typed_count: 1,
+                last_visit_time: Some(Timestamp::from_millis(3000.0)),
+            },
+        ]
+    );
+}
+
+#[test]
+fn visit_histogram() {
+    // 2022-11-09T23:30:00Z and 2022-11-10T00:30:00Z
+    let times = [1668036600000.0, 1668040200000.0].map(Timestamp::from_millis);
+    let day = |day| Day {
+        year: 2022,
+        month: 11,
+        day,
+    };
+    assert_eq!(
+        visits_per_day(times, 0).into_iter().collect::<Vec<_>>(),
+        [(day(9), 1), (day(10), 1)]
+    );
+    assert_eq!(
+        visits_per_day(times, 60).into_iter().collect::<Vec<_>>(),
+        [(day(10), 2)]
+    );
+    assert_eq!(
+        Day::from_timestamp(Timestamp::from_millis(-1.0), 0),
+        Day {
+            year: 1969,
+            month: 12,
+            day: 31
+        }
+    );
+}
+
+#[test]
+fn frecency_scores() {
+    let day = 24.0 * 60.0 * 60.0 * 1000.0;
+    let now = Timestamp::from_millis(100.0 * day);
+    let visit = |days_ago: f64, transition| VisitItem {
+        id: "1".to_owned(),
+        visit_id: "1".to_owned(),
+        visit_time: Some(Timestamp::from_millis(now.as_millis() - days_ago * day)),
+        referring_visit_id: "0".to_owned(),
+        transition,
+        is_local: Some(true),
+    };
+    let visits = [
+        visit(1.0, TransitionType::Typed),
+        visit(10.0, TransitionType::Link),
+        visit(2.0, TransitionType::Reload),
+    ];
+    assert_eq!(frecency(&visits, now), 200.0 + 70.0);
+
+    let items = [
+        history_item(
+            "old",
+            "https://old.example.com/",
+            now.as_millis() - 60.0 * day,
+            20,
+            0,
+        ),
+        history_item(
+            "recent",
+            "https://new.example.com/",
+            now.as_millis() - day,
+            5,
+            2,
+        ),
+        history_item(
+            "rare",
+            "https://rare.example.com/",
+            now.as_millis() - day,
+            1,
+            0,
+        ),
+    ];
+    let top: Vec<_> = top_sites(&items, now, 2)
+        .into_iter()
+        .map(|item| item.id.as_str())
+        .collect();
+    assert_eq!(top, ["recent", "old"]);
+}
+
+#[test]
+fn history_pages_narrow_end_time() {
+    let mut pages = HistoryPages::new(Query {
+        start_time: Some(Timestamp::UNIX_EPOCH),
+        max_results: Some(2),
+        ..Default::default()
+    });
+    assert_eq!(pages.query().unwrap().end_time, None);
+
+    let page = pages.advance(vec![
+        history_item("1", "https://a.example/", 5000.5, 1, 0),
+        history_item("2", "https://b.example/", 4000.5, 1, 0),
+    ]);
+    assert_eq!(page.len(), 2);
+    assert_eq!(
+        pages.query().unwrap().end_time,
+        Some(Timestamp::from_millis(4001.0))
+    );
+
+    let page = pages.advance(vec![
+        history_item("2", "https://b.example/", 4000.5, 1, 0),
+        history_item("3", "https://c.example/", 3000.0, 1, 0),
+    ]);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page[0].id, "3");
+    assert_eq!(
+        pages.query().unwrap().end_time,
+        Some(Timestamp::from_millis(3000.0))
+    );
+
+    assert!(pages.advance(vec![]).is_empty());
+    assert!(pages.query().is_none());
+}
+
+#[test]
+fn history_pages_single_results() {
+    let mut pages = HistoryPages::new(Query {
+        start_time: Some(Timestamp::UNIX_EPOCH),
+        max_results: Some(1),
+        ..Default::default()
+    });
+    let a = history_item("1", "https://a.example/", 5000.7, 1, 0);
+    let b = history_item("2", "https://b.example/", 5000.2, 1, 0);
+    assert_eq!(pages.advance(vec![a.clone()])[0].id, "1");
+    // `a` is visited before the rounded up end time and shows up again.
+    assert!(pages.advance(vec![a]).is_empty());
+    assert_eq!(
+        pages.query().unwrap().end_time,
+        Some(Timestamp::from_millis(5000.7))
+    );
+    assert_eq!(pages.advance(vec![b.clone()])[0].id, "2");
+    assert!(pages.advance(vec![b]).is_empty());
+    assert!(pages.advance(vec![]).is_empty());
+    assert!(pages.query().is_none());
+}
+
+#[test]
+fn history_pages_stop_without_progress() {
+    let mut pages = HistoryPages::new(Query {
+        start_time: Some(Timestamp::UNIX_EPOCH),
+        max_results: Some(1),
+        ..Default::default()
+    });
+    let item = history_item("1", "https://a.example/", 3000.0, 1, 0);
+    assert_eq!(pages.advance(vec![item.clone()]).len(), 1);
+    // A backend that includes the end time returns the same item forever.
+    assert!(pages.advance(vec![item]).is_empty());
+    assert!(pages.query().is_none());
+}
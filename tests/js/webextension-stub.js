@@ -0,0 +1,939 @@
+// An in-memory stand-in for the `chrome` and `browser` globals of an extension,
+// so that the wrappers can be tested with `wasm-bindgen-test` in Node.js.
+//
+// It models the state of the tabs, bookmarks, history, downloads and
+// contextualIdentities APIs as well as runtime messages and ports, dispatches
+// their events and rejects invalid calls with errors like the ones of the browser.
+// Both globals refer to the same APIs. By default they behave like Chrome,
+// `install({ firefox: true })` switches to Firefox: it adds `runtime.getBrowserInfo()`
+// and `contextualIdentities`, and omits Chrome-only events and tab properties.
+
+class Event {
+  constructor() {
+    this.listeners = [];
+  }
+
+  addListener(listener) {
+    if (!this.hasListener(listener)) {
+      this.listeners.push(listener);
+    }
+  }
+
+  removeListener(listener) {
+    this.listeners = this.listeners.filter((l) => l !== listener);
+  }
+
+  hasListener(listener) {
+    return this.listeners.includes(listener);
+  }
+
+  hasListeners() {
+    return this.listeners.length > 0;
+  }
+
+  dispatch(...args) {
+    for (const listener of [...this.listeners]) {
+      listener(...args);
+    }
+  }
+}
+
+const WINDOW_ID_CURRENT = -2;
+const DAY_MS = 24 * 60 * 60 * 1000;
+
+const copy = (value) => (value === undefined ? undefined : structuredClone(value));
+
+const isSet = (value) => value !== undefined && value !== null;
+
+const fail = (message) => Promise.reject(new Error(message));
+
+const events = (...names) => Object.fromEntries(names.map((name) => [name, new Event()]));
+
+/// Matches `text` against a pattern in which `*` matches any sequence of characters.
+function matchesPattern(pattern, text) {
+  const escaped = pattern.split("*").map((part) => part.replace(/[.+?^${}()|[\]\\]/g, "\\$&"));
+  return new RegExp(`^${escaped.join(".*")}$`).test(text ?? "");
+}
+
+function matchesWords(query, ...fields) {
+  const haystack = fields.map((field) => (field ?? "").toLowerCase());
+  return query
+    .toLowerCase()
+    .split(/\s+/)
+    .filter((word) => word)
+    .every((word) => haystack.some((field) => field.includes(word)));
+}
+
+let state;
+
+function initialState() {
+  const folder = (id, index, title) => ({ id, parentId: "0", index, title, dateAdded: 0, children: [] });
+  return {
+    now: 1_700_000_000_000,
+    nextId: 1,
+    windowId: 1,
+    tabs: [],
+    bookmarks: {
+      id: "0",
+      title: "",
+      dateAdded: 0,
+      children: [folder("1", 0, "Bookmarks bar"), folder("2", 1, "Other bookmarks")],
+    },
+    nextBookmarkId: 3,
+    history: [],
+    nextHistoryId: 1,
+    downloads: [],
+    nextDownloadId: 1,
+    identities: [],
+    nextIdentityId: 1,
+  };
+}
+
+function nextId(key) {
+  const id = state[key];
+  state[key] += 1;
+  return id;
+}
+
+// tabs
+
+const tabsEvents = events(
+  "onActivated",
+  "onAttached",
+  "onCreated",
+  "onDetached",
+  "onHighlighted",
+  "onMoved",
+  "onRemoved",
+  "onReplaced",
+  "onUpdated",
+  "onZoomChange",
+);
+
+function findTab(tabId) {
+  const tab = state.tabs.find((tab) => tab.id === tabId);
+  if (!tab) {
+    throw new Error(`No tab with id: ${tabId}.`);
+  }
+  return tab;
+}
+
+function windowTabs(windowId) {
+  return state.tabs.filter((tab) => tab.windowId === windowId).sort((a, b) => a.index - b.index);
+}
+
+function reindex(windowId) {
+  windowTabs(windowId).forEach((tab, index) => {
+    tab.index = index;
+  });
+}
+
+function activate(tab) {
+  for (const other of windowTabs(tab.windowId)) {
+    other.active = other === tab;
+    other.highlighted = other === tab;
+  }
+  tabsEvents.onActivated.dispatch({ tabId: tab.id, windowId: tab.windowId });
+}
+
+/// Adds a tab to its window. Like in the browser, a new active tab is already active in `onCreated`.
+function addTab(tab, active) {
+  state.tabs.push(tab);
+  reindex(tab.windowId);
+  if (active) {
+    tab.active = true;
+    tab.highlighted = true;
+  }
+  tabsEvents.onCreated.dispatch(copy(tab));
+  if (active) {
+    activate(tab);
+  }
+}
+
+const tabs = {
+  ...tabsEvents,
+  TAB_ID_NONE: -1,
+
+  async get(tabId) {
+    return copy(findTab(tabId));
+  },
+
+  async query(queryInfo = {}) {
+    const windowId = queryInfo.windowId === WINDOW_ID_CURRENT ? state.windowId : queryInfo.windowId;
+    const flags = ["active", "audible", "autoDiscardable", "discarded", "highlighted", "index", "pinned", "status"];
+    return copy(
+      state.tabs
+        .filter((tab) => flags.every((flag) => !isSet(queryInfo[flag]) || queryInfo[flag] === tab[flag]))
+        .filter((tab) => !isSet(windowId) || tab.windowId === windowId)
+        .filter((tab) => !isSet(queryInfo.currentWindow) || queryInfo.currentWindow === (tab.windowId === state.windowId))
+        .filter((tab) => !isSet(queryInfo.url) || matchesPattern(queryInfo.url, tab.url))
+        .filter((tab) => !isSet(queryInfo.title) || matchesPattern(queryInfo.title, tab.title))
+        .sort((a, b) => a.windowId - b.windowId || a.index - b.index),
+    );
+  },
+
+  async create(createProperties = {}) {
+    const windowId = createProperties.windowId ?? state.windowId;
+    const url = createProperties.url ?? "chrome://newtab/";
+    const tab = {
+      id: nextId("nextId"),
+      windowId,
+      index: windowTabs(windowId).length,
+      active: false,
+      audible: false,
+      autoDiscardable: true,
+      discarded: false,
+      ...(state.firefox ? {} : { groupId: -1 }),
+      highlighted: false,
+      incognito: false,
+      pinned: createProperties.pinned ?? false,
+      status: "complete",
+      title: url,
+      url,
+    };
+    addTab(tab, (createProperties.active ?? true) || tab.index === 0);
+    return copy(tab);
+  },
+
+  async update(tabId, updateProperties = {}) {
+    const tab = findTab(tabId ?? tabs.activeTabId());
+    const changeInfo = {};
+    for (const key of ["autoDiscardable", "highlighted", "openerTabId", "pinned", "url"]) {
+      if (isSet(updateProperties[key]) && tab[key] !== updateProperties[key]) {
+        tab[key] = updateProperties[key];
+        changeInfo[key] = updateProperties[key];
+      }
+    }
+    if (isSet(updateProperties.url)) {
+      tab.title = updateProperties.url;
+      changeInfo.title = updateProperties.url;
+    }
+    if (isSet(updateProperties.muted)) {
+      changeInfo.mutedInfo = { muted: updateProperties.muted };
+    }
+    if (updateProperties.active && !tab.active) {
+      activate(tab);
+    }
+    if (Object.keys(changeInfo).length > 0) {
+      tabsEvents.onUpdated.dispatch(tab.id, changeInfo, copy(tab));
+    }
+    return copy(tab);
+  },
+
+  async remove(tabIds) {
+    const ids = Array.isArray(tabIds) ? tabIds : [tabIds];
+    const removed = ids.map(findTab);
+    for (const tab of removed) {
+      state.tabs = state.tabs.filter((other) => other !== tab);
+      reindex(tab.windowId);
+      const remaining = windowTabs(tab.windowId);
+      if (tab.active && remaining.length > 0) {
+        activate(remaining[Math.min(tab.index, remaining.length - 1)]);
+      }
+      tabsEvents.onRemoved.dispatch(tab.id, { windowId: tab.windowId, isWindowClosing: false });
+    }
+  },
+
+  async reload(tabId) {
+    findTab(tabId ?? tabs.activeTabId());
+  },
+
+  async duplicate(tabId) {
+    const original = findTab(tabId);
+    const tab = { ...copy(original), id: nextId("nextId"), index: original.index + 0.5, openerTabId: original.id };
+    addTab(tab, true);
+    return copy(tab);
+  },
+
+  async move(tabIds, moveProperties) {
+    const ids = Array.isArray(tabIds) ? tabIds : [tabIds];
+    const moved = ids.map(findTab);
+    moved.forEach((tab, i) => {
+      const fromIndex = tab.index;
+      const others = windowTabs(tab.windowId).filter((other) => other !== tab);
+      const toIndex = moveProperties.index === -1 ? others.length : Math.min(moveProperties.index + i, others.length);
+      others.splice(toIndex, 0, tab);
+      others.forEach((other, index) => {
+        other.index = index;
+      });
+      tabsEvents.onMoved.dispatch(tab.id, { windowId: tab.windowId, fromIndex, toIndex });
+    });
+    return copy(moved);
+  },
+
+  activeTabId() {
+    return windowTabs(state.windowId).find((tab) => tab.active)?.id;
+  },
+};
+
+// bookmarks
+
+const bookmarksEvents = events(
+  "onChanged",
+  "onChildrenReordered",
+  "onCreated",
+  "onImportBegan",
+  "onImportEnded",
+  "onMoved",
+  "onRemoved",
+);
+
+function* walk(node) {
+  yield node;
+  for (const child of node.children ?? []) {
+    yield* walk(child);
+  }
+}
+
+function findBookmark(id) {
+  for (const node of walk(state.bookmarks)) {
+    if (node.id === id) {
+      return node;
+    }
+  }
+  throw new Error("Can't find bookmark for id.");
+}
+
+function checkModifiable(node) {
+  if (node.id === "0" || node.parentId === "0") {
+    throw new Error("Can't modify the root bookmark folders.");
+  }
+}
+
+function withoutChildren(node) {
+  const { children, ...rest } = node;
+  return copy(rest);
+}
+
+function reindexChildren(folder) {
+  folder.children.forEach((child, index) => {
+    child.index = index;
+    child.parentId = folder.id;
+  });
+}
+
+function detach(node) {
+  const parent = findBookmark(node.parentId);
+  parent.children.splice(node.index, 1);
+  parent.dateGroupModified = state.now;
+  reindexChildren(parent);
+  return parent;
+}
+
+function insert(folder, index, node) {
+  if (!folder.children) {
+    throw new Error("Parameter 'parentId' does not specify a folder.");
+  }
+  const position = isSet(index) ? index : folder.children.length;
+  if (position > folder.children.length) {
+    throw new Error("Index out of bounds.");
+  }
+  folder.children.splice(position, 0, node);
+  folder.dateGroupModified = state.now;
+  reindexChildren(folder);
+}
+
+const bookmarks = {
+  ...bookmarksEvents,
+
+  async get(idOrIdList) {
+    const ids = Array.isArray(idOrIdList) ? idOrIdList : [idOrIdList];
+    return ids.map((id) => withoutChildren(findBookmark(id)));
+  },
+
+  async getChildren(id) {
+    return findBookmark(id).children.map(withoutChildren);
+  },
+
+  async getRecent(numberOfItems) {
+    return [...walk(state.bookmarks)]
+      .filter((node) => node.url)
+      .sort((a, b) => b.dateAdded - a.dateAdded || Number(b.id) - Number(a.id))
+      .slice(0, numberOfItems)
+      .map(withoutChildren);
+  },
+
+  async getTree() {
+    return [copy(state.bookmarks)];
+  },
+
+  async getSubTree(id) {
+    return [copy(findBookmark(id))];
+  },
+
+  async search(query) {
+    const details = typeof query === "string" ? { query } : query;
+    return [...walk(state.bookmarks)]
+      .filter((node) => node.id !== "0" && node.parentId !== "0")
+      .filter((node) => !isSet(details.query) || matchesWords(details.query, node.title, node.url))
+      .filter((node) => !isSet(details.title) || node.title === details.title)
+      .filter((node) => !isSet(details.url) || node.url === details.url)
+      .map(withoutChildren);
+  },
+
+  async create(bookmark = {}) {
+    const parent = findBookmark(bookmark.parentId ?? "2");
+    const node = {
+      id: String(nextId("nextBookmarkId")),
+      title: bookmark.title ?? "",
+      dateAdded: state.now,
+      ...(isSet(bookmark.url) ? { url: bookmark.url } : { children: [] }),
+    };
+    insert(parent, bookmark.index, node);
+    bookmarksEvents.onCreated.dispatch(node.id, withoutChildren(node));
+    return withoutChildren(node);
+  },
+
+  async update(id, changes) {
+    const node = findBookmark(id);
+    checkModifiable(node);
+    if (isSet(changes.url)) {
+      if (!node.url) {
+        throw new Error("Can't set URL of a bookmark folder.");
+      }
+      node.url = changes.url;
+    }
+    if (isSet(changes.title)) {
+      node.title = changes.title;
+    }
+    bookmarksEvents.onChanged.dispatch(id, { title: node.title, ...(node.url ? { url: node.url } : {}) });
+    return withoutChildren(node);
+  },
+
+  async move(id, destination) {
+    const node = findBookmark(id);
+    checkModifiable(node);
+    const oldParentId = node.parentId;
+    const oldIndex = node.index;
+    const parentId = destination.parentId ?? oldParentId;
+    if ([...walk(node)].some((descendant) => descendant.id === parentId)) {
+      throw new Error("Can't move a folder into itself or its descendants.");
+    }
+    const parent = findBookmark(parentId);
+    let index = destination.index;
+    if (isSet(index) && parentId === oldParentId && index > oldIndex) {
+      index -= 1;
+    }
+    detach(node);
+    insert(parent, index, node);
+    bookmarksEvents.onMoved.dispatch(id, { parentId, index: node.index, oldParentId, oldIndex });
+    return withoutChildren(node);
+  },
+
+  async remove(id) {
+    const node = findBookmark(id);
+    checkModifiable(node);
+    if (node.children?.length) {
+      throw new Error("Can't remove non-empty folder (use recursive to remove a folder with children).");
+    }
+    detach(node);
+    bookmarksEvents.onRemoved.dispatch(id, { parentId: node.parentId, index: node.index, node: copy(node) });
+  },
+
+  async removeTree(id) {
+    const node = findBookmark(id);
+    checkModifiable(node);
+    detach(node);
+    bookmarksEvents.onRemoved.dispatch(id, { parentId: node.parentId, index: node.index, node: copy(node) });
+  },
+};
+
+// history
+
+const historyEvents = events("onVisited", "onVisitRemoved");
+
+function updateHistoryItem(entry) {
+  entry.item.visitCount = entry.visits.length;
+  entry.item.typedCount = entry.visits.filter((visit) => visit.transition === "typed").length;
+  entry.item.lastVisitTime = Math.max(...entry.visits.map((visit) => visit.visitTime));
+}
+
+/// Records a visit, as if the user navigated to `url`.
+export function visit(url, title, transition) {
+  let entry = state.history.find((entry) => entry.item.url === url);
+  if (!entry) {
+    entry = { item: { id: String(nextId("nextHistoryId")), url, title: "" }, visits: [] };
+    state.history.push(entry);
+  }
+  if (isSet(title)) {
+    entry.item.title = title;
+  }
+  entry.visits.push({
+    id: entry.item.id,
+    visitId: String(nextId("nextHistoryId")),
+    visitTime: state.now,
+    referringVisitId: "0",
+    transition: transition ?? "link",
+    isLocal: true,
+  });
+  updateHistoryItem(entry);
+  historyEvents.onVisited.dispatch(copy(entry.item));
+}
+
+const history = {
+  ...historyEvents,
+
+  async search(query) {
+    const startTime = query.startTime ?? state.now - DAY_MS;
+    const inPeriod = (time) => startTime <= time && (!isSet(query.endTime) || time < query.endTime);
+    return state.history
+      .filter((entry) => entry.visits.some((visit) => inPeriod(visit.visitTime)))
+      .filter((entry) => matchesWords(query.text ?? "", entry.item.url, entry.item.title))
+      .map((entry) => copy(entry.item))
+      .sort((a, b) => b.lastVisitTime - a.lastVisitTime)
+      .slice(0, query.maxResults ?? 100);
+  },
+
+  async getVisits(details) {
+    return copy(state.history.find((entry) => entry.item.url === details.url)?.visits ?? []);
+  },
+
+  async addUrl(details) {
+    visit(details.url, details.title, details.transition);
+  },
+
+  async deleteUrl(details) {
+    state.history = state.history.filter((entry) => entry.item.url !== details.url);
+    historyEvents.onVisitRemoved.dispatch({ allHistory: false, urls: [details.url] });
+  },
+
+  async deleteRange(range) {
+    const urls = [];
+    for (const entry of state.history) {
+      entry.visits = entry.visits.filter((visit) => visit.visitTime < range.startTime || visit.visitTime > range.endTime);
+      if (entry.visits.length === 0) {
+        urls.push(entry.item.url);
+      } else {
+        updateHistoryItem(entry);
+      }
+    }
+    state.history = state.history.filter((entry) => entry.visits.length > 0);
+    historyEvents.onVisitRemoved.dispatch({ allHistory: false, urls });
+  },
+
+  async deleteAll() {
+    state.history = [];
+    historyEvents.onVisitRemoved.dispatch({ allHistory: true, urls: [] });
+  },
+};
+
+// downloads
+
+const downloadsEvents = events("onChanged", "onCreated", "onDeterminingFilename", "onErased");
+
+const toIso = (millis) => new Date(millis).toISOString();
+
+function findDownload(downloadId) {
+  const item = state.downloads.find((item) => item.id === downloadId);
+  if (!item) {
+    throw new Error(`Invalid download id: ${downloadId}.`);
+  }
+  return item;
+}
+
+/// Applies `changes` to the download and dispatches `onChanged` with the delta.
+export function changeDownload(downloadId, changes) {
+  const item = findDownload(downloadId);
+  const delta = { id: downloadId };
+  for (const [key, value] of Object.entries(changes)) {
+    if (item[key] !== value) {
+      delta[key] = { previous: item[key], current: value };
+      item[key] = value;
+    }
+  }
+  downloadsEvents.onChanged.dispatch(delta);
+}
+
+function matchesDownload(item, query) {
+  const exact = ["bytesReceived", "danger", "endTime", "error", "exists", "fileSize", "filename", "finalUrl", "id", "mime", "paused", "startTime", "state", "totalBytes", "url"];
+  const terms = query.query ?? [];
+  return (
+    exact.every((key) => !isSet(query[key]) || query[key] === item[key]) &&
+    terms.every((term) => {
+      const excluded = term.startsWith("-");
+      const word = (excluded ? term.slice(1) : term).toLowerCase();
+      const found = item.filename.toLowerCase().includes(word) || item.url.toLowerCase().includes(word);
+      return found !== excluded;
+    }) &&
+    (!isSet(query.startedAfter) || item.startTime > query.startedAfter) &&
+    (!isSet(query.startedBefore) || item.startTime < query.startedBefore) &&
+    (!isSet(query.endedAfter) || (item.endTime ?? "") > query.endedAfter) &&
+    (!isSet(query.endedBefore) || (isSet(item.endTime) && item.endTime < query.endedBefore)) &&
+    (!isSet(query.totalBytesGreater) || item.totalBytes > query.totalBytesGreater) &&
+    (!isSet(query.totalBytesLess) || item.totalBytes < query.totalBytesLess) &&
+    (!isSet(query.filenameRegex) || new RegExp(query.filenameRegex).test(item.filename)) &&
+    (!isSet(query.urlRegex) || new RegExp(query.urlRegex).test(item.url)) &&
+    (!isSet(query.finalUrlRegex) || new RegExp(query.finalUrlRegex).test(item.finalUrl))
+  );
+}
+
+function searchDownloads(query = {}) {
+  const items = state.downloads.filter((item) => matchesDownload(item, query));
+  for (const key of [...(query.orderBy ?? [])].reverse()) {
+    const descending = key.startsWith("-");
+    const field = descending ? key.slice(1) : key;
+    items.sort((a, b) => (a[field] < b[field] ? -1 : a[field] > b[field] ? 1 : 0) * (descending ? -1 : 1));
+  }
+  const limit = query.limit ?? 1000;
+  return limit === 0 ? items : items.slice(0, limit);
+}
+
+const downloads = {
+  ...downloadsEvents,
+
+  async search(query) {
+    return copy(searchDownloads(query));
+  },
+
+  async download(options) {
+    const filename = options.filename ?? (new URL(options.url).pathname.split("/").pop() || "download");
+    const item = {
+      id: nextId("nextDownloadId"),
+      url: options.url,
+      finalUrl: options.url,
+      filename,
+      incognito: false,
+      danger: "safe",
+      mime: "",
+      startTime: toIso(state.now),
+      state: "in_progress",
+      paused: false,
+      canResume: false,
+      bytesReceived: 0,
+      totalBytes: 0,
+      fileSize: 0,
+      exists: true,
+    };
+    state.downloads.push(item);
+    downloadsEvents.onCreated.dispatch(copy(item));
+    return item.id;
+  },
+
+  // Like in Firefox, the following methods throw synchronously
+  // for an unknown download ID.
+
+  pause(downloadId) {
+    const item = findDownload(downloadId);
+    if (item.state !== "in_progress") {
+      return fail("Download must be in progress.");
+    }
+    changeDownload(downloadId, { paused: true, canResume: true });
+    return Promise.resolve();
+  },
+
+  resume(downloadId) {
+    const item = findDownload(downloadId);
+    if (!item.canResume) {
+      return fail("Download must be resumable.");
+    }
+    changeDownload(downloadId, { state: "in_progress", paused: false });
+    return Promise.resolve();
+  },
+
+  cancel(downloadId) {
+    const item = findDownload(downloadId);
+    if (item.state === "in_progress") {
+      changeDownload(downloadId, {
+        state: "interrupted",
+        error: "USER_CANCELED",
+        paused: false,
+        canResume: false,
+        endTime: toIso(state.now),
+      });
+    }
+    return Promise.resolve();
+  },
+
+  erase(query) {
+    const erased = searchDownloads(query).map((item) => item.id);
+    state.downloads = state.downloads.filter((item) => !erased.includes(item.id));
+    for (const id of erased) {
+      downloadsEvents.onErased.dispatch(id);
+    }
+    return Promise.resolve(erased);
+  },
+
+  removeFile(downloadId) {
+    const item = findDownload(downloadId);
+    if (item.state !== "complete") {
+      return fail("Download must be complete.");
+    }
+    changeDownload(downloadId, { exists: false });
+    return Promise.resolve();
+  },
+
+  open(downloadId) {
+    findDownload(downloadId);
+    return Promise.resolve();
+  },
+
+  show(downloadId) {
+    findDownload(downloadId);
+    return Promise.resolve();
+  },
+
+  showDefaultFolder() {
+    return undefined;
+  },
+
+  getFileIcon(downloadId) {
+    findDownload(downloadId);
+    return Promise.resolve(undefined);
+  },
+
+  acceptDanger(downloadId) {
+    changeDownload(downloadId, { danger: "accepted" });
+    return Promise.resolve();
+  },
+};
+
+// contextualIdentities
+
+const identityEvents = events("onCreated", "onRemoved", "onUpdated");
+
+const COLOR_CODES = {
+  blue: "#37adff",
+  turquoise: "#00c79a",
+  green: "#51cd00",
+  yellow: "#ffcb00",
+  orange: "#ff9f00",
+  red: "#ff613d",
+  pink: "#ff4bda",
+  purple: "#af51f5",
+  toolbar: "#7c7c7d",
+};
+
+function findIdentity(cookieStoreId) {
+  const identity = state.identities.find((identity) => identity.cookieStoreId === cookieStoreId);
+  if (!identity) {
+    throw new Error(`Invalid contextual identity: ${cookieStoreId}`);
+  }
+  return identity;
+}
+
+function setIdentityDetails(identity, details) {
+  for (const key of ["name", "color", "icon"]) {
+    if (isSet(details[key])) {
+      identity[key] = details[key];
+    }
+  }
+  identity.colorCode = COLOR_CODES[identity.color];
+  identity.iconUrl = `resource://usercontext-content/${identity.icon}.svg`;
+}
+
+const contextualIdentities = {
+  ...identityEvents,
+
+  async query(details = {}) {
+    return copy(state.identities.filter((identity) => !isSet(details.name) || identity.name === details.name));
+  },
+
+  async get(cookieStoreId) {
+    return copy(findIdentity(cookieStoreId));
+  },
+
+  async create(details) {
+    const identity = { cookieStoreId: `firefox-container-${nextId("nextIdentityId")}` };
+    setIdentityDetails(identity, details);
+    state.identities.push(identity);
+    identityEvents.onCreated.dispatch({ contextualIdentity: copy(identity) });
+    return copy(identity);
+  },
+
+  async update(cookieStoreId, details) {
+    const identity = findIdentity(cookieStoreId);
+    setIdentityDetails(identity, details);
+    identityEvents.onUpdated.dispatch({ contextualIdentity: copy(identity) });
+    return copy(identity);
+  },
+
+  async remove(cookieStoreId) {
+    const identity = findIdentity(cookieStoreId);
+    state.identities = state.identities.filter((other) => other !== identity);
+    identityEvents.onRemoved.dispatch({ contextualIdentity: copy(identity) });
+    return copy(identity);
+  },
+};
+
+// runtime
+
+const NO_RECEIVER = "Could not establish connection. Receiving end does not exist.";
+
+/// One end of a connection, messages are delivered asynchronously like in the browser.
+class Port {
+  constructor(name, sender) {
+    this.name = name;
+    if (sender) {
+      this.sender = sender;
+    }
+    this.onMessage = new Event();
+    this.onDisconnect = new Event();
+    this.connected = true;
+  }
+
+  postMessage(message) {
+    if (!this.connected) {
+      throw new Error("Attempting to use a disconnected port object");
+    }
+    const other = this.other;
+    const copied = copy(message);
+    queueMicrotask(() => other.connected && other.onMessage.dispatch(copied, other));
+  }
+
+  disconnect() {
+    if (this.connected) {
+      this.connected = false;
+      this.other.closeBy(undefined);
+    }
+  }
+
+  /// Disconnects this end because the other end is gone, like Firefox it sets `port.error`.
+  closeBy(error) {
+    this.connected = false;
+    if (error) {
+      this.error = error;
+    }
+    queueMicrotask(() => this.onDisconnect.dispatch(this));
+  }
+}
+
+const runtime = {
+  ...events("onConnect", "onInstalled", "onMessage", "onStartup"),
+  id: "stub-extension-id",
+
+  /// Accepts `sendMessage(message)` as well as `sendMessage(extensionId, message, options)`.
+  sendMessage(...args) {
+    const message = args.length > 1 ? args[1] : args[0];
+    return deliver(message, { id: runtime.id, url: runtime.getURL("popup.html") });
+  },
+
+  connect(extensionId, connectInfo = {}) {
+    const port = new Port(connectInfo.name ?? "");
+    const other = new Port(port.name, { id: runtime.id, url: runtime.getURL("background.html") });
+    port.other = other;
+    other.other = port;
+    queueMicrotask(() => {
+      if (runtime.onConnect.hasListeners()) {
+        runtime.onConnect.dispatch(other);
+      } else {
+        other.connected = false;
+        port.closeBy(new Error(NO_RECEIVER));
+      }
+    });
+    return port;
+  },
+
+  getManifest() {
+    return { manifest_version: 3, name: "web-extensions stub", version: "0.0.0" };
+  },
+
+  getURL(path) {
+    return `chrome-extension://${runtime.id}/${path.replace(/^\//, "")}`;
+  },
+};
+
+/// Calls the `runtime.onMessage` listeners like the browser does.
+///
+/// Resolves with the first response, or with `undefined` if no listener keeps the channel
+/// open by returning `true` or a promise, and rejects if nobody is listening.
+function deliver(message, sender) {
+  return new Promise((resolve, reject) => {
+    if (!runtime.onMessage.hasListeners()) {
+      reject(new Error(NO_RECEIVER));
+      return;
+    }
+    let answered = false;
+    const sendResponse = (response) => {
+      if (!answered) {
+        answered = true;
+        resolve(copy(response));
+      }
+    };
+    let pending = false;
+    for (const listener of [...runtime.onMessage.listeners]) {
+      const result = listener(copy(message), copy(sender), sendResponse);
+      if (result === true) {
+        pending = true;
+      } else if (result instanceof Promise) {
+        pending = true;
+        result.then(sendResponse, reject);
+      }
+    }
+    if (!pending) {
+      sendResponse(undefined);
+    }
+  });
+}
+
+// windows
+
+const windowsEvents = events("onBoundsChanged", "onCreated", "onFocusChanged", "onRemoved");
+
+const windows = {
+  ...windowsEvents,
+  WINDOW_ID_CURRENT,
+  WINDOW_ID_NONE: -1,
+};
+
+const api = { bookmarks, downloads, history, runtime, tabs, windows };
+
+/// The events that Firefox does not support, by API.
+const CHROME_ONLY_EVENTS = {
+  bookmarks: ["onChildrenReordered", "onImportBegan", "onImportEnded"],
+  downloads: ["onDeterminingFilename"],
+  windows: ["onBoundsChanged"],
+};
+
+const allEvents = { bookmarks: bookmarksEvents, downloads: downloadsEvents, windows: windowsEvents };
+
+async function getBrowserInfo() {
+  return { name: "Stub", vendor: "web-extensions", version: "1.0", buildID: "0" };
+}
+
+/// Installs the globals and resets the state of all APIs,
+/// while listeners that are still registered are kept.
+///
+/// With `{ firefox: true }` the APIs behave like the ones of Firefox.
+export function install({ firefox = false } = {}) {
+  state = initialState();
+  state.firefox = firefox;
+  for (const [namespace, names] of Object.entries(CHROME_ONLY_EVENTS)) {
+    for (const name of names) {
+      if (firefox) {
+        delete api[namespace][name];
+      } else {
+        api[namespace][name] = allEvents[namespace][name];
+      }
+    }
+  }
+  if (firefox) {
+    runtime.getBrowserInfo = getBrowserInfo;
+    api.contextualIdentities = contextualIdentities;
+  } else {
+    delete runtime.getBrowserInfo;
+    delete api.contextualIdentities;
+  }
+  globalThis.chrome = api;
+  globalThis.browser = api;
+}
+
+/// Sends a message to the extension as a content script in the tab `tabId` would.
+export function messageFromTab(tabId, message) {
+  const tab = findTab(tabId);
+  return deliver(message, { id: runtime.id, tab: copy(tab), frameId: 0, url: tab.url });
+}
+
+/// Sets the time in milliseconds since the epoch that is used for new items.
+export function setNow(millis) {
+  state.now = millis;
+}
+
+/// Dispatches an event of an API, e.g. `dispatch("bookmarks", "onImportBegan", [])`.
+export function dispatch(namespace, event, args) {
+  api[namespace][event].dispatch(...args);
+}
+
+/// Returns the number of listeners that are registered for an event of an API.
+export function listenerCount(namespace, event) {
+  return api[namespace][event].listeners.length;
+}
@@ -0,0 +1,529 @@
+//! Exercises the wrappers against the stubbed extension APIs of `tests/js/webextension-stub.js`.
+//!
+//! Run with `cargo test --target wasm32-unknown-unknown --test node`.
+
+#![cfg(target_arch = "wasm32")]
+
+use std::path::Path;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+use web_extensions::{
+    bookmarks::{self, BookmarkId, CreateDetails, Destination},
+    downloads::{self, DownloadOptions, DownloadQuery, InterruptReason, State},
+    history::{self, Query},
+    runtime::{self, ConnectInfo},
+    tabs::{self, CreateProperties, MoveProperties, QueryDetails, UpdateProperties},
+    windows::{self, WindowId},
+    Error, Namespace, Timestamp,
+};
+
+#[wasm_bindgen(module = "/tests/js/webextension-stub.js")]
+extern "C" {
+    /// Installs the `chrome` and `browser` globals with a fresh state.
+    fn install();
+
+    #[wasm_bindgen(js_name = install)]
+    fn install_with(options: &js_sys::Object);
+
+    #[wasm_bindgen(js_name = setNow)]
+    fn set_now(millis: f64);
+
+    fn dispatch(namespace: &str, event: &str, args: js_sys::Array);
+
+    #[wasm_bindgen(js_name = listenerCount)]
+    fn listener_count(namespace: &str, event: &str) -> u32;
+
+    #[wasm_bindgen(catch, js_name = messageFromTab)]
+    async fn message_from_tab(tab_id: i32, message: &JsValue) -> Result<JsValue, JsValue>;
+}
+
+/// Like [`install`], but the APIs behave like the ones of Firefox.
+fn install_firefox() {
+    let options = js_sys::Object::new();
+    js_sys::Reflect::set(&options, &"firefox".into(), &true.into()).unwrap();
+    install_with(&options);
+}
+
+fn current_window_tabs() -> QueryDetails<'static> {
+    QueryDetails {
+        window_id: Some(WindowId::CURRENT),
+        ..Default::default()
+    }
+}
+
+fn urls(tabs: &[tabs::Tab]) -> Vec<&str> {
+    tabs.iter().map(|tab| tab.url.as_deref().unwrap()).collect()
+}
+
+#[wasm_bindgen_test]
+async fn browser_info() {
+    install_firefox();
+    let info = web_extensions::browser_info().await.unwrap();
+    assert_eq!(info.namespace, Namespace::Browser);
+    assert_eq!(info.name, "Stub");
+    assert_eq!(info.manifest_version, 3);
+}
+
+#[wasm_bindgen_test]
+async fn tabs() {
+    install();
+    let mut created = tabs::on_created().stream();
+    let mut removed = tabs::on_removed().stream();
+
+    let first = tabs::create(CreateProperties {
+        active: true,
+        url: "https://example.com/",
+    })
+    .await
+    .unwrap();
+    assert_eq!(created.next_event().await, first);
+    let second = tabs::create(CreateProperties {
+        active: false,
+        url: "https://www.rust-lang.org/",
+    })
+    .await
+    .unwrap();
+    assert_eq!(second.index, 1);
+    assert!(first.active && !second.active);
+
+    let pinned = tabs::update(
+        second.id.unwrap(),
+        &UpdateProperties {
+            pinned: Some(true),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+    assert!(pinned.pinned);
+
+    tabs::move_tabs(
+        &[second.id.unwrap()],
+        MoveProperties {
+            index: 0,
+            window_id: None,
+        },
+    )
+    .await
+    .unwrap();
+    let all = tabs::query(&current_window_tabs()).await.unwrap();
+    assert_eq!(
+        urls(&all),
+        ["https://www.rust-lang.org/", "https://example.com/"]
+    );
+    let details = QueryDetails {
+        url: Some("https://*.rust-lang.org/*"),
+        ..current_window_tabs()
+    };
+    assert_eq!(tabs::query(&details).await.unwrap().len(), 1);
+
+    tabs::remove(&[first.id.unwrap()]).await.unwrap();
+    let (tab_id, info) = removed.next_event().await;
+    assert_eq!(Some(tab_id), first.id);
+    assert!(!info.is_window_closing);
+    assert!(tabs::get(second.id.unwrap()).await.unwrap().active);
+
+    assert!(matches!(
+        tabs::get(first.id.unwrap()).await,
+        Err(Error::Js(_))
+    ));
+}
+
+#[wasm_bindgen_test]
+async fn event_streams() {
+    install();
+    let on_activated = tabs::on_activated();
+    let mut activated = on_activated.stream();
+    let listener = on_activated.add_listener(|_| {});
+    assert_eq!(listener_count("tabs", "onActivated"), 2);
+
+    let first = tabs::create(CreateProperties {
+        active: true,
+        url: "https://example.com/",
+    })
+    .await
+    .unwrap();
+    let second = tabs::create(CreateProperties {
+        active: true,
+        url: "https://www.rust-lang.org/",
+    })
+    .await
+    .unwrap();
+    assert_eq!(Some(activated.next_event().await.tab_id), first.id);
+    assert_eq!(Some(activated.next_event().await.tab_id), second.id);
+
+    drop(activated);
+    assert_eq!(listener_count("tabs", "onActivated"), 1);
+    drop(listener);
+    assert_eq!(listener_count("tabs", "onActivated"), 0);
+
+    // Events that cannot be decoded are skipped instead of aborting the listener.
+    let mut moved = bookmarks::on_moved().stream();
+    let invalid_info = js_sys::Object::new();
+    dispatch(
+        "bookmarks",
+        "onMoved",
+        js_sys::Array::of2(&"1".into(), &invalid_info),
+    );
+    let destination = bookmarks::create(&CreateDetails {
+        title: Some("Rust"),
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+    let bookmark = bookmarks::create(&CreateDetails {
+        url: Some("https://www.rust-lang.org/"),
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+    bookmarks::move_node(
+        &bookmark.id,
+        &Destination {
+            parent_id: Some(&destination.id),
+            index: None,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(moved.next_event().await.0, bookmark.id);
+
+    // Firefox omits the properties of tabs it does not support.
+    let mut created = tabs::on_created().stream();
+    let tab = js_sys::JSON::parse(
+        r#"{ "id": 9, "index": 0, "windowId": 1, "active": false,
+             "highlighted": false, "incognito": false, "pinned": false }"#,
+    )
+    .unwrap();
+    dispatch("tabs", "onCreated", js_sys::Array::of1(&tab));
+    let tab = created.next_event().await;
+    assert_eq!(tab.group_id, -1);
+    assert!(tab.auto_discardable && !tab.discarded);
+}
+
+#[wasm_bindgen_test]
+async fn bookmarks() {
+    install();
+    let mut created = bookmarks::on_created().stream();
+    let mut moved = bookmarks::on_moved().stream();
+
+    let bar = BookmarkId::from("1");
+    let folder = bookmarks::create(&CreateDetails {
+        parent_id: Some(&bar),
+        title: Some("Rust"),
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+    assert_eq!(created.next_event().await.0, folder.id);
+    for (title, url) in [
+        ("The Book", "https://doc.rust-lang.org/book/"),
+        ("Docs", "https://docs.rs/"),
+    ] {
+        bookmarks::create(&CreateDetails {
+            parent_id: Some(&folder.id),
+            title: Some(title),
+            url: Some(url),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    }
+    let children = bookmarks::get_children(&folder.id).await.unwrap();
+    bookmarks::move_node(
+        &children[0].id,
+        &Destination {
+            parent_id: None,
+            index: Some(2),
+        },
+    )
+    .await
+    .unwrap();
+    let (id, info) = moved.next_event().await;
+    assert_eq!(id, children[0].id);
+    assert_eq!(info.index, 1);
+
+    let found = bookmarks::search(&"RUST book".into()).await.unwrap();
+    assert_eq!(found.len(), 1);
+    let tree = bookmarks::get_tree().await.unwrap();
+    assert_eq!(
+        tree[0].folder_path(&found[0].id).as_deref(),
+        Some("Bookmarks bar/Rust")
+    );
+
+    assert!(bookmarks::remove(&folder.id).await.is_err());
+    bookmarks::remove_tree(&folder.id).await.unwrap();
+    assert_eq!(bookmarks::get_tree().await.unwrap()[0].count().bookmarks, 0);
+
+    let mut import_began = bookmarks::on_import_began().unwrap().stream();
+    dispatch("bookmarks", "onImportBegan", js_sys::Array::new());
+    import_began.next_event().await;
+}
+
+#[wasm_bindgen_test]
+async fn history() {
+    install();
+    let hour = 60.0 * 60.0 * 1000.0;
+    let mut visited = history::on_visited().stream();
+    let mut visit_removed = history::on_visit_removed().stream();
+
+    set_now(100.0 * hour);
+    history::add_url("https://www.rust-lang.org/")
+        .await
+        .unwrap();
+    set_now(110.0 * hour);
+    history::add_url("https://docs.rs/").await.unwrap();
+    history::add_url("https://www.rust-lang.org/")
+        .await
+        .unwrap();
+    assert_eq!(
+        visited.next_event().await.url.as_deref(),
+        Some("https://www.rust-lang.org/")
+    );
+
+    let items = history::search(&Query {
+        start_time: Some(Timestamp::from_millis(0.0)),
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0].visit_count, Some(2));
+    assert_eq!(history::search(&"".into()).await.unwrap().len(), 2);
+
+    history::delete_range(
+        Timestamp::from_millis(109.0 * hour),
+        Timestamp::from_millis(111.0 * hour),
+    )
+    .await
+    .unwrap();
+    let removed = visit_removed.next_event().await;
+    assert_eq!(removed.urls.unwrap(), ["https://docs.rs/"]);
+    assert_eq!(
+        history::get_visits("https://www.rust-lang.org/")
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+
+    history::delete_all().await.unwrap();
+    assert!(visit_removed.next_event().await.all_history);
+}
+
+#[wasm_bindgen_test]
+async fn downloads() {
+    install();
+    let mut changed = downloads::on_changed().stream();
+
+    let id = downloads::download(&DownloadOptions {
+        url: "https://example.com/files/report.pdf",
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+    let items = downloads::search(&DownloadQuery::default()).await.unwrap();
+    assert_eq!(items[0].filename, Path::new("report.pdf"));
+    assert_eq!(items[0].state, State::InProgress);
+
+    downloads::pause(id).await.unwrap();
+    let delta = changed.next_event().await;
+    assert_eq!(delta.paused.unwrap().current, Some(true));
+    downloads::cancel(id).await.unwrap();
+    let delta = changed.next_event().await;
+    assert_eq!(
+        delta.error.unwrap().current,
+        Some(InterruptReason::UserCanceled)
+    );
+    assert!(delta.end_time.unwrap().current.is_some());
+    assert!(downloads::remove_file(id).await.is_err());
+
+    assert_eq!(downloads::erase(&"report".into()).await.unwrap(), [id]);
+    assert!(downloads::pause(id).await.is_err());
+
+    async fn download(url: &str) -> downloads::DownloadId {
+        let options = DownloadOptions {
+            url,
+            ..Default::default()
+        };
+        downloads::download(&options).await.unwrap()
+    }
+    let photo = download("https://example.com/files/photo.jpg").await;
+    let notes = download("https://example.com/files/notes.txt").await;
+    let tracker = downloads::DownloadTracker::start(&"photo".into())
+        .await
+        .unwrap();
+    let archive = download("https://example.com/files/archive.zip").await;
+    tracker.refresh().await.unwrap();
+    assert!(tracker.get(photo).is_some() && tracker.get(archive).is_some());
+    assert!(tracker.get(notes).is_none());
+    drop(tracker);
+
+    // Undecodable downloads keep the default filename instead of aborting.
+    let suggestions = downloads::on_determining_filename()
+        .unwrap()
+        .add_listener(|item| {
+            Some(async move {
+                Some(downloads::FilenameSuggestion {
+                    filename: item.filename.to_string_lossy().into_owned(),
+                    conflict_action: None,
+                })
+            })
+        });
+    let suggest = js_sys::Function::new_no_args("");
+    dispatch(
+        "downloads",
+        "onDeterminingFilename",
+        js_sys::Array::of2(&JsValue::NULL, &suggest),
+    );
+    drop(suggestions);
+}
+
+#[wasm_bindgen_test]
+async fn ports() {
+    install();
+    // Nobody is listening yet.
+    let mut port = runtime::connect(&ConnectInfo::default()).unwrap();
+    assert!(port.on_disconnect().await.is_some());
+    assert!(port.on_disconnect().await.is_some());
+    assert!(port.messages::<u32>().next_message().await.is_none());
+
+    let mut connections = runtime::on_connect().stream();
+    let port = runtime::connect(&ConnectInfo {
+        name: Some("counter"),
+        ..Default::default()
+    })
+    .unwrap();
+    let mut remote = connections.next_event().await;
+    assert_eq!(remote.name(), "counter");
+    assert_eq!(
+        remote.sender().unwrap().id.as_deref(),
+        Some("stub-extension-id")
+    );
+    assert!(port.sender().is_none());
+
+    let mut messages = remote.messages::<u32>();
+    port.post_message(&1).unwrap();
+    port.post_message("two").unwrap();
+    assert_eq!(messages.next_message().await.unwrap().unwrap(), 1);
+    assert!(messages.next_message().await.unwrap().is_err());
+    let mut replies = port.messages::<u32>();
+    remote.post_message(&2).unwrap();
+    assert_eq!(replies.next_message().await.unwrap().unwrap(), 2);
+
+    port.disconnect();
+    assert!(messages.next_message().await.is_none());
+    assert!(remote.on_disconnect().await.is_none());
+    assert!(remote.on_disconnect().await.is_none());
+    assert!(remote.messages::<u32>().next_message().await.is_none());
+    assert!(remote.post_message(&3).is_err());
+}
+
+#[wasm_bindgen_test]
+async fn messaging() {
+    install();
+    assert!(matches!(
+        runtime::send_message::<_, u32>(&1).await,
+        Err(Error::NoReceiver)
+    ));
+
+    let _doubled = runtime::on_message().add_listener(|n: u32, sender| {
+        assert_eq!(sender.id.as_deref(), Some("stub-extension-id"));
+        Some(async move { n * 2 })
+    });
+    let _echoed = runtime::on_message().add_listener(|text: String, _| Some(async move { text }));
+    assert_eq!(runtime::send_message::<_, u32>(&21).await.unwrap(), 42);
+    assert_eq!(
+        runtime::send_message::<_, String>(&"hello").await.unwrap(),
+        "hello"
+    );
+    // Nobody answers, so the response is missing.
+    assert!(runtime::send_message::<_, u32>(&[1, 2]).await.is_err());
+    assert!(runtime::send_message::<_, Option<u32>>(&true)
+        .await
+        .unwrap()
+        .is_none());
+
+    // JSON objects only have string keys, so the error is logged
+    // and the sender does not wait for a response.
+    let _unserializable = runtime::on_message().add_listener(|_: bool, _| {
+        Some(async move { std::collections::BTreeMap::from([((1, 2), 3)]) })
+    });
+    assert!(runtime::send_message::<_, Option<u32>>(&true)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[wasm_bindgen_test]
+async fn firefox_mode() {
+    install_firefox();
+    assert!(matches!(
+        bookmarks::on_import_began(),
+        Err(Error::UnsupportedEvent {
+            namespace: "bookmarks",
+            event: "onImportBegan"
+        })
+    ));
+    assert!(bookmarks::on_children_reordered().is_err());
+    assert!(downloads::on_determining_filename().is_err());
+    assert!(windows::on_bounds_changed().is_err());
+
+    let tab = tabs::create(CreateProperties {
+        active: true,
+        url: "https://example.com/",
+    })
+    .await
+    .unwrap();
+    assert_eq!(tab.id, Some(1.into()));
+    assert_eq!(tab.group_id, -1);
+
+    // Content scripts report their tab, which lacks the Chrome-only properties.
+    let _listener = runtime::on_message().add_listener(|message: String, sender| {
+        let tab = sender.tab.unwrap();
+        Some(async move { format!("{message} from {}", tab.url.unwrap()) })
+    });
+    let response = message_from_tab(1, &"hello".into()).await.unwrap();
+    assert_eq!(response, "hello from https://example.com/");
+
+    install();
+    assert!(bookmarks::on_import_began().is_ok());
+    assert!(windows::on_bounds_changed().is_ok());
+}
+
+#[cfg(feature = "firefox")]
+#[wasm_bindgen_test]
+async fn contextual_identities() {
+    use web_extensions::contextual_identities::*;
+
+    install_firefox();
+    let work = create(&CreateDetails {
+        name: "Work",
+        color: Color::Blue,
+        icon: Icon::Briefcase,
+    })
+    .await
+    .unwrap();
+    assert_eq!(work.color_code, "#37adff");
+    let updated = update(
+        &work.cookie_store_id,
+        &UpdateDetails {
+            name: None,
+            color: Some(Color::Red),
+            icon: None,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(updated.color, Color::Red);
+    assert_eq!(updated.name, "Work");
+    assert_eq!(
+        query(&QueryDetails { name: Some("Work") })
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+    assert_eq!(remove(&work.cookie_store_id).await.unwrap(), updated);
+    assert!(get(&work.cookie_store_id).await.is_err());
+}
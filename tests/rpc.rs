@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use web_extensions::{rpc::*, runtime::MessageSender};
+
+mod util;
+use util::block_on;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Add(i32, i32);
+
+impl Request for Add {
+    const METHOD: &'static str = "add";
+    type Response = i32;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Divide {
+    dividend: i32,
+    divisor: i32,
+}
+
+impl Request for Divide {
+    const METHOD: &'static str = "divide";
+    type Response = i32;
+}
+
+fn dispatcher() -> Dispatcher {
+    Dispatcher::new()
+        .handle(|Add(a, b), _| async move { Ok(a + b) })
+        .handle(|Divide { dividend, divisor }, _| async move {
+            dividend
+                .checked_div(divisor)
+                .ok_or_else(|| RemoteError::new("division by zero"))
+        })
+}
+
+fn sender() -> MessageSender {
+    serde_json::from_value(json!({ "id": "extension-id" })).unwrap()
+}
+
+fn call(request: serde_json::Value) -> Option<serde_json::Value> {
+    let request = serde_json::from_value(request).unwrap();
+    let response = dispatcher().dispatch(request, sender())?;
+    Some(serde_json::to_value(block_on(response)).unwrap())
+}
+
+#[test]
+fn dispatch_ok() {
+    assert_eq!(
+        call(json!({ "id": 7, "method": "add", "params": [1, 2] })),
+        Some(json!({ "id": 7, "result": 3 }))
+    );
+}
+
+#[test]
+fn dispatch_remote_error() {
+    assert_eq!(
+        call(json!({ "id": 8, "method": "divide", "params": { "dividend": 1, "divisor": 0 } })),
+        Some(json!({ "id": 8, "error": { "message": "division by zero" } }))
+    );
+}
+
+#[test]
+fn dispatch_invalid_params() {
+    let response = call(json!({ "id": 9, "method": "add", "params": "1 + 2" })).unwrap();
+    assert_eq!(response["id"], 9);
+    assert!(response["error"]["message"].is_string());
+}
+
+#[test]
+fn dispatch_unknown_method() {
+    assert_eq!(
+        call(json!({ "id": 10, "method": "subtract", "params": [1, 2] })),
+        None
+    );
+}
@@ -0,0 +1,66 @@
+use web_extensions::{runtime::*, tabs::TabId};
+
+#[test]
+fn message_sender_deserialize() {
+    let json = r#"{
+        "documentId": "8E3D5BAF5E0D9F3E1B5C0A7E4E2F3D1C",
+        "frameId": 0,
+        "id": "abcdefghijklmnopabcdefghijklmnop",
+        "origin": "https://example.com",
+        "tab": {
+            "active": true,
+            "autoDiscardable": true,
+            "discarded": false,
+            "groupId": -1,
+            "highlighted": true,
+            "id": 5,
+            "incognito": false,
+            "index": 0,
+            "pinned": false,
+            "windowId": 1
+        },
+        "url": "https://example.com/page"
+    }"#;
+    let sender: MessageSender = serde_json::from_str(json).unwrap();
+    assert_eq!(sender.frame_id, Some(0));
+    assert_eq!(sender.origin.as_deref(), Some("https://example.com"));
+    assert_eq!(sender.tab.unwrap().id, Some(TabId::from(5)));
+    assert_eq!(sender.tls_channel_id, None);
+}
+
+#[test]
+fn message_sender_deserialize_from_extension_page() {
+    let json = r#"{
+        "id": "abcdefghijklmnopabcdefghijklmnop",
+        "origin": "chrome-extension://abcdefghijklmnopabcdefghijklmnop",
+        "url": "chrome-extension://abcdefghijklmnopabcdefghijklmnop/popup.html"
+    }"#;
+    let sender: MessageSender = serde_json::from_str(json).unwrap();
+    assert!(sender.tab.is_none());
+    assert!(sender.frame_id.is_none());
+}
+
+#[test]
+fn message_sender_deserialize_from_firefox() {
+    // Firefox has no tab groups and omits the properties it does not support.
+    let json = r#"{
+        "frameId": 0,
+        "id": "extension@example.com",
+        "tab": {
+            "active": true,
+            "highlighted": true,
+            "id": 5,
+            "incognito": false,
+            "index": 0,
+            "pinned": false,
+            "windowId": 1
+        },
+        "url": "https://example.com/page"
+    }"#;
+    let sender: MessageSender = serde_json::from_str(json).unwrap();
+    let tab = sender.tab.unwrap();
+    assert_eq!(tab.id, Some(TabId::from(5)));
+    assert_eq!(tab.group_id, -1);
+    assert!(tab.auto_discardable);
+    assert!(!tab.discarded);
+}
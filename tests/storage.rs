@@ -0,0 +1,133 @@
+use web_extensions::storage::*;
+
+mod util;
+use util::*;
+
+#[test]
+fn access_level_serde() {
+    assert_json_serde_test_cases(&[
+        JSONSerdeTestCase {
+            value: AccessLevel::TrustedContexts,
+            json: r#""TRUSTED_CONTEXTS""#,
+        },
+        JSONSerdeTestCase {
+            value: AccessLevel::TrustedAndUntrustedContexts,
+            json: r#""TRUSTED_AND_UNTRUSTED_CONTEXTS""#,
+        },
+    ])
+}
+
+#[test]
+fn storage_changes_deserialize() {
+    let json = r#"{
+        "theme": { "oldValue": "light", "newValue": "dark" },
+        "counter": { "newValue": 1 }
+    }"#;
+    let changes: StorageChanges = serde_json::from_str(json).unwrap();
+    let theme = changes["theme"].clone().into_typed::<String>().unwrap();
+    assert_eq!(theme.old_value.as_deref(), Some("light"));
+    assert_eq!(theme.new_value.as_deref(), Some("dark"));
+    let counter = changes["counter"].clone().into_typed::<u32>().unwrap();
+    assert_eq!(counter.old_value, None);
+    assert_eq!(counter.new_value, Some(1));
+    assert!(changes["counter"].clone().into_typed::<String>().is_err());
+}
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Settings {
+    theme: String,
+    font_size: u32,
+}
+
+fn settings_schema() -> Schema<Settings> {
+    Schema::new(3)
+        // v1 stored the theme as a boolean
+        .migration(1, |mut data| {
+            let dark = data["dark"].take().as_bool().unwrap_or_default();
+            Ok(serde_json::json!({ "theme": if dark { "dark" } else { "light" } }))
+        })
+        // v2 had no font size
+        .migration(2, |mut data| {
+            data["font_size"] = 14.into();
+            Ok(data)
+        })
+}
+
+#[test]
+fn schema_round_trip() {
+    let schema = settings_schema();
+    let settings = Settings {
+        theme: "dark".into(),
+        font_size: 12,
+    };
+    let stored = schema.encode(&settings).unwrap();
+    assert_eq!(stored.version, 3);
+    assert_eq!(schema.decode(stored).unwrap(), settings);
+}
+
+#[test]
+fn schema_migrates_old_versions() {
+    let schema = settings_schema();
+    let stored = Versioned {
+        version: 1,
+        data: serde_json::json!({ "dark": true }),
+    };
+    assert_eq!(
+        schema.decode(stored).unwrap(),
+        Settings {
+            theme: "dark".into(),
+            font_size: 14,
+        }
+    );
+}
+
+#[test]
+fn schema_migrates_unversioned_values() {
+    let schema = settings_schema()
+        // v0 was stored before the schema was adopted
+        .migration(0, |data| {
+            Ok(serde_json::json!({ "dark": data["theme"] == "dark" }))
+        });
+    let legacy = serde_json::json!({ "theme": "dark" });
+    assert_eq!(
+        schema.decode_value(legacy).unwrap(),
+        Settings {
+            theme: "dark".into(),
+            font_size: 14,
+        }
+    );
+    let settings = Settings {
+        theme: "light".into(),
+        font_size: 12,
+    };
+    let stored = serde_json::to_value(schema.encode(&settings).unwrap()).unwrap();
+    assert_eq!(schema.decode_value(stored).unwrap().font_size, 12);
+    assert!(matches!(
+        settings_schema().decode_value(serde_json::json!("dark")),
+        Err(web_extensions::Error::MissingMigration(0))
+    ));
+}
+
+#[test]
+fn schema_rejects_unknown_versions() {
+    let schema = settings_schema();
+    let newer = Versioned {
+        version: 4,
+        data: serde_json::json!({}),
+    };
+    assert!(matches!(
+        schema.decode(newer),
+        Err(web_extensions::Error::UnsupportedSchemaVersion {
+            found: 4,
+            supported: 3
+        })
+    ));
+    let unmigratable = Versioned {
+        version: 0,
+        data: serde_json::json!({}),
+    };
+    assert!(matches!(
+        schema.decode(unmigratable),
+        Err(web_extensions::Error::MissingMigration(0))
+    ));
+}
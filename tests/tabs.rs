@@ -1,4 +1,4 @@
-use web_extensions::tabs::*;
+use web_extensions::{tabs::*, windows::WindowId};
 
 mod util;
 use util::*;
@@ -18,8 +18,8 @@ fn status_serde() {
 }
 
 #[test]
-fn window_type_serialize() {
-    assert_json_serialize_test_cases(&[
+fn window_type_serde() {
+    assert_json_serde_test_cases(&[
         JSONSerdeTestCase {
             value: WindowType::Normal,
             json: r#""normal""#,
@@ -32,9 +32,85 @@ fn window_type_serialize() {
             value: WindowType::Panel,
             json: r#""panel""#,
         },
+        JSONSerdeTestCase {
+            value: WindowType::App,
+            json: r#""app""#,
+        },
         JSONSerdeTestCase {
             value: WindowType::Devtools,
             json: r#""devtools""#,
         },
     ])
 }
+
+#[test]
+fn move_properties_serialize() {
+    assert_json_serialize_test_cases(&[
+        JSONSerdeTestCase {
+            value: MoveProperties {
+                index: -1,
+                window_id: None,
+            },
+            json: r#"{"index":-1,"windowId":null}"#,
+        },
+        JSONSerdeTestCase {
+            value: MoveProperties {
+                index: 2,
+                window_id: Some(WindowId::from(7)),
+            },
+            json: r#"{"index":2,"windowId":7}"#,
+        },
+    ])
+}
+
+#[test]
+fn query_details_serialize_window_id() {
+    let all_windows = serde_json::to_value(QueryDetails::default()).unwrap();
+    assert_eq!(all_windows["windowId"], serde_json::Value::Null);
+    let current_window = serde_json::to_value(QueryDetails {
+        window_id: Some(WindowId::CURRENT),
+        ..Default::default()
+    })
+    .unwrap();
+    assert_eq!(current_window["windowId"], -2);
+}
+
+#[test]
+fn send_message_options_serialize() {
+    assert_json_serialize_test_cases(&[
+        JSONSerdeTestCase {
+            value: SendMessageOptions::default(),
+            json: r#"{"documentId":null,"frameId":null}"#,
+        },
+        JSONSerdeTestCase {
+            value: SendMessageOptions {
+                document_id: None,
+                frame_id: Some(0),
+            },
+            json: r#"{"documentId":null,"frameId":0}"#,
+        },
+    ])
+}
+
+#[test]
+fn event_streams_yield_typed_events() {
+    fn assert_stream<S: futures_core::Stream<Item = T> + Unpin, T>() {}
+
+    assert_stream::<web_extensions::EventStream<Tab>, _>();
+    assert_stream::<web_extensions::EventStream<ActiveInfo>, _>();
+    assert_stream::<web_extensions::EventStream<(TabId, ChangeInfo, Tab)>, _>();
+}
+
+#[test]
+fn listeners_outlive_their_event() {
+    fn assert_static<T: 'static>() {}
+
+    assert_static::<OnCreatedEventListener>();
+    assert_static::<OnUpdatedEventListener>();
+
+    // Compiles only because the listener does not borrow the temporary event.
+    #[allow(dead_code)]
+    fn listen() -> OnCreatedEventListener {
+        on_created().add_listener(|_| {})
+    }
+}
@@ -0,0 +1,117 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use web_extensions::{downloads::DownloadQuery, Timestamp};
+
+mod util;
+use util::*;
+
+#[test]
+fn timestamp_deserialize() {
+    let expected = Timestamp::from_millis(1667988930000.0);
+    assert_json_deserialize_eq("1667988930000", &expected);
+    assert_json_deserialize_eq("1667988930000.0", &expected);
+    assert_json_deserialize_eq(r#""2022-11-09T10:15:30.000Z""#, &expected);
+    assert_json_deserialize_eq(r#""2022-11-09T10:15:30Z""#, &expected);
+    assert_json_deserialize_eq(r#""2022-11-09T11:15:30+01:00""#, &expected);
+    assert_json_deserialize_eq(r#""2022-11-09T05:15:30-0500""#, &expected);
+    assert_json_deserialize_eq(
+        r#""2022-11-09T10:15:30.123456Z""#,
+        &Timestamp::from_millis(1667988930123.456),
+    );
+    assert!(serde_json::from_str::<Timestamp>(r#""yesterday""#).is_err());
+    assert!("2022-13-01T00:00:00Z".parse::<Timestamp>().is_err());
+    assert!("2024-02-31T00:00:00Z".parse::<Timestamp>().is_err());
+    assert!("2023-02-29T00:00:00Z".parse::<Timestamp>().is_err());
+    assert!("2023-04-31T00:00:00Z".parse::<Timestamp>().is_err());
+    assert!("1900-02-29".parse::<Timestamp>().is_err());
+    assert!("2024-02-29T00:00:00Z".parse::<Timestamp>().is_ok());
+    assert!("2000-02-29".parse::<Timestamp>().is_ok());
+    assert!("99999999999-01-01".parse::<Timestamp>().is_err());
+    assert!("2022-01-01T00:00:00+99999999999999999:00"
+        .parse::<Timestamp>()
+        .is_err());
+}
+
+#[test]
+fn timestamp_serialize() {
+    assert_json_serialize_eq(&Timestamp::from_millis(1667988930000.0), "1667988930000");
+    assert_json_serialize_eq(&Timestamp::from_millis(1.5), "1.5");
+    assert_eq!(
+        Timestamp::from_millis(1667988930123.9).to_iso8601(),
+        "2022-11-09T10:15:30.123Z"
+    );
+    assert_eq!(
+        Timestamp::from_millis(-1.0).to_string(),
+        "1969-12-31T23:59:59.999Z"
+    );
+
+    let query = DownloadQuery::default().started_after(Timestamp::from_millis(1667988930000.0));
+    let json = serde_json::to_value(&query).unwrap();
+    assert_eq!(json["startedAfter"], "2022-11-09T10:15:30.000Z");
+    assert!(json["startedBefore"].is_null());
+}
+
+#[test]
+fn timestamp_system_time() {
+    let time = UNIX_EPOCH + Duration::from_millis(1667988930123);
+    assert_eq!(
+        Timestamp::from(time),
+        Timestamp::from_millis(1667988930123.0)
+    );
+    assert_eq!(SystemTime::try_from(Timestamp::from(time)).unwrap(), time);
+    let before = UNIX_EPOCH - Duration::from_secs(1);
+    assert_eq!(Timestamp::from(before), Timestamp::from_millis(-1000.0));
+    assert_eq!(
+        SystemTime::try_from(Timestamp::from_millis(-1000.0)).unwrap(),
+        before
+    );
+
+    let huge: Timestamp = serde_json::from_str("1e300").unwrap();
+    assert!(SystemTime::try_from(huge).is_err());
+    assert!(SystemTime::try_from(Timestamp::from_millis(-1e300)).is_err());
+    assert!(SystemTime::try_from(Timestamp::from_millis(f64::NAN)).is_err());
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn timestamp_chrono() {
+    let time: chrono::DateTime<chrono::Utc> = "2022-11-09T10:15:30.123Z".parse().unwrap();
+    let timestamp = Timestamp::from(time);
+    assert_eq!(timestamp, Timestamp::from_millis(1667988930123.0));
+    assert_eq!(
+        chrono::DateTime::<chrono::Utc>::try_from(timestamp).unwrap(),
+        time
+    );
+    let before = Timestamp::from_millis(-1500.0);
+    assert_eq!(
+        chrono::DateTime::<chrono::Utc>::try_from(before)
+            .unwrap()
+            .timestamp_millis(),
+        -1500
+    );
+
+    for millis in [1e300, -1e300, 1e20, -1e20] {
+        let timestamp = Timestamp::from_millis(millis);
+        assert!(chrono::DateTime::<chrono::Utc>::try_from(timestamp).is_err());
+    }
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn timestamp_time() {
+    let time = time::OffsetDateTime::from_unix_timestamp_nanos(1_667_988_930_123_000_000).unwrap();
+    let timestamp = Timestamp::from(time);
+    assert_eq!(timestamp, Timestamp::from_millis(1667988930123.0));
+    assert_eq!(time::OffsetDateTime::try_from(timestamp).unwrap(), time);
+    let before = Timestamp::from_millis(-1500.0);
+    assert_eq!(
+        time::OffsetDateTime::try_from(before)
+            .unwrap()
+            .unix_timestamp_nanos(),
+        -1_500_000_000
+    );
+
+    for millis in [1e300, -1e300, 1e20, -1e20] {
+        let timestamp = Timestamp::from_millis(millis);
+        assert!(time::OffsetDateTime::try_from(timestamp).is_err());
+    }
+}
@@ -1,25 +1,36 @@
-use std::{cmp::PartialEq, fmt::Debug};
+use std::{
+    cmp::PartialEq,
+    fmt::Debug,
+    future::Future,
+    pin::pin,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+};
 
 pub fn assert_json_serialize_eq<'a, T>(left: &'a T, right: &'a str)
 where
     T: serde::Serialize + Debug,
 {
     assert_eq!(
-        serde_json::to_string(left).expect(&format!("failed to serialize {:?} to JSON", left)),
+        serde_json::to_string(left)
+            .unwrap_or_else(|_| panic!("failed to serialize {:?} to JSON", left)),
         right
     );
 }
 
+#[allow(dead_code)]
 pub fn assert_json_deserialize_eq<'a, T>(left: &'a str, right: &'a T)
 where
     T: serde::Deserialize<'a> + PartialEq + Debug,
 {
     assert_eq!(
-        &serde_json::from_str::<T>(left).expect(&format!("failed to deserialize JSON {}", left)),
+        &serde_json::from_str::<T>(left)
+            .unwrap_or_else(|_| panic!("failed to deserialize JSON {}", left)),
         right
     )
 }
 
+#[allow(dead_code)]
 pub fn assert_json_serde_eq<'a, T>(left: &'a T, right: &'a str)
 where
     T: serde::Serialize + serde::Deserialize<'a> + PartialEq + Debug,
@@ -44,6 +55,7 @@ where
     }
 }
 
+#[allow(dead_code)]
 pub fn assert_json_serde_test_cases<'a, T, I>(tcs: I)
 where
     T: 'a + serde::Serialize + serde::Deserialize<'a> + PartialEq + Debug,
@@ -53,3 +65,26 @@ where
         assert_json_serde_eq(&tc.value, tc.json);
     }
 }
+
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+/// A waker that does nothing, `Waker::noop()` needs Rust 1.85.
+#[allow(dead_code)]
+pub fn noop_waker() -> Waker {
+    Waker::from(Arc::new(NoopWaker))
+}
+
+/// Runs a future that completes without waiting, e.g. one of the fake backend.
+#[allow(dead_code)]
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = noop_waker();
+    match future.as_mut().poll(&mut Context::from_waker(&waker)) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("the future is expected to complete immediately"),
+    }
+}
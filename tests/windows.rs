@@ -0,0 +1,82 @@
+use web_extensions::{
+    tabs::TabId,
+    windows::{Window, WindowId, WindowState, WindowType},
+};
+
+mod util;
+use util::*;
+
+#[test]
+fn window_state_serde() {
+    assert_json_serde_test_cases(&[
+        JSONSerdeTestCase {
+            value: WindowState::Normal,
+            json: r#""normal""#,
+        },
+        JSONSerdeTestCase {
+            value: WindowState::Minimized,
+            json: r#""minimized""#,
+        },
+        JSONSerdeTestCase {
+            value: WindowState::Maximized,
+            json: r#""maximized""#,
+        },
+        JSONSerdeTestCase {
+            value: WindowState::Fullscreen,
+            json: r#""fullscreen""#,
+        },
+        JSONSerdeTestCase {
+            value: WindowState::LockedFullscreen,
+            json: r#""locked-fullscreen""#,
+        },
+        JSONSerdeTestCase {
+            value: WindowState::Docked,
+            json: r#""docked""#,
+        },
+    ])
+}
+
+#[test]
+fn window_state_try_from() {
+    assert_eq!(
+        WindowState::try_from("docked".to_owned()).unwrap(),
+        WindowState::Docked
+    );
+    assert!(WindowState::try_from("tiled".to_owned()).is_err());
+}
+
+#[test]
+fn window_deserialize_populated() {
+    let json = r#"{
+        "alwaysOnTop": false,
+        "focused": true,
+        "height": 800,
+        "id": 3,
+        "incognito": false,
+        "left": 0,
+        "state": "normal",
+        "tabs": [{
+            "active": true,
+            "autoDiscardable": true,
+            "discarded": false,
+            "groupId": -1,
+            "highlighted": true,
+            "id": 12,
+            "incognito": false,
+            "index": 0,
+            "pinned": false,
+            "windowId": 3
+        }],
+        "top": 0,
+        "type": "normal",
+        "width": 1200
+    }"#;
+    let window: Window = serde_json::from_str(json).unwrap();
+    assert_eq!(window.id, Some(WindowId::from(3)));
+    assert_eq!(window.state, Some(WindowState::Normal));
+    assert_eq!(window.type_, Some(WindowType::Normal));
+    let tabs = window.tabs.unwrap();
+    assert_eq!(tabs.len(), 1);
+    assert_eq!(tabs[0].id, Some(TabId::from(12)));
+    assert_eq!(tabs[0].window_id, WindowId::from(3));
+}